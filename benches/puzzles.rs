@@ -0,0 +1,151 @@
+//! Benchmarks parsing and each part of every registered puzzle against its real input.
+//!
+//! Days come from the same registry as the `aoc23` binary and are timed by the same runner as
+//! `aoc23 --bench`, so the statistics of both can be compared directly.
+//!
+//! ```text
+//! cargo bench --bench puzzles -- [FILTER] [--runs N] [--warmup N] [--timeout SECONDS]
+//! ```
+//!
+//! Only days whose id (e.g. `2023/day5`) contains FILTER are run. Every variant of a part is
+//! benchmarked. A part that exceeds the timeout over all of its runs is too slow to benchmark
+//! and is skipped. The benchmark fails when any part fails.
+
+use std::{path::Path, process::ExitCode, time::Duration};
+
+use aoc23::{
+    input::{self, Source},
+    registry::SOLUTIONS,
+    report::Outcome,
+    timing::Elapsed,
+    Options, Variants,
+};
+
+struct Config {
+    filter: Option<String>,
+    runs: u32,
+    warmup: u32,
+    timeout: Duration,
+}
+
+impl Config {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut config = Config {
+            filter: None,
+            runs: 10,
+            warmup: 3,
+            timeout: Duration::from_secs(30),
+        };
+        while let Some(arg) = args.next() {
+            let mut value =
+                |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+            match arg.as_str() {
+                // Passed by `cargo bench` itself.
+                "--bench" => {}
+                "--runs" => config.runs = parse(&value("--runs")?)?,
+                "--warmup" => config.warmup = parse(&value("--warmup")?)?,
+                "--timeout" => config.timeout = parse_timeout(&value("--timeout")?)?,
+                flag if flag.starts_with('-') => return Err(format!("unknown flag {}", flag)),
+                filter => config.filter = Some(filter.to_string()),
+            }
+        }
+        Ok(config)
+    }
+}
+
+fn parse<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{:?} is not a valid number", value))
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("{:?} is not a positive number of seconds", value))
+}
+
+fn main() -> ExitCode {
+    let config = match Config::from_args(std::env::args().skip(1)) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let options = Options {
+        bench: Some(config.runs.max(1)),
+        warmup: config.warmup,
+        timeout: Some(config.timeout),
+        variants: Variants::All,
+        ..Options::default()
+    };
+    let source = Source::Dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(input::DEFAULT_DIR));
+
+    let mut solutions = SOLUTIONS
+        .iter()
+        .filter(|solution| {
+            config
+                .filter
+                .as_ref()
+                .is_none_or(|filter| solution.id().contains(filter.as_str()))
+        })
+        .collect::<Vec<_>>();
+    solutions.sort_by_key(|solution| (solution.info.year, solution.info.day));
+
+    let mut success = true;
+    for solution in solutions {
+        let info = &solution.info;
+        println!("{}", solution.id());
+        let input = match source.load(info.year, info.day) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("  skipped - {}\n", err);
+                continue;
+            }
+        };
+
+        let report = solution.run(&input, &options);
+        success &= !report
+            .parts
+            .iter()
+            .any(|part| matches!(part.outcome, Outcome::Failed(_)));
+        let Some(stats) = &report.parse_stats else {
+            // The parts all fail with the parse error.
+            let error = report.parts.iter().find_map(|part| part.outcome.error());
+            println!(
+                "  {:<20}  failed - {}\n",
+                "parse",
+                error.unwrap_or("unknown error")
+            );
+            continue;
+        };
+        println!("  {:<20}  {}", "parse", stats);
+        for part in &report.parts {
+            let name = format!("part{} [{}]", part.part.number(), part.variant);
+            match (&part.outcome, &part.stats) {
+                (Outcome::Solved(_), Some(stats)) => println!("  {:<20}  {}", name, stats),
+                (Outcome::Solved(_), None) => {}
+                (Outcome::Failed(error), _) => println!("  {:<20}  failed - {}", name, error),
+                (Outcome::NotImplemented, _) => println!("  {:<20}  not implemented", name),
+                (Outcome::TimedOut, _) => {
+                    println!(
+                        "  {:<20}  skipped - timeout after {}",
+                        name,
+                        Elapsed(config.timeout)
+                    )
+                }
+            }
+        }
+        println!();
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,209 @@
+//! Allocation counting for `--alloc-stats`.
+//!
+//! [`Counting`] wraps the system allocator and, once [`enable`]d, counts the allocations made on
+//! each thread. The binary installs it as the global allocator, and [`track`] reports what a
+//! closure allocated on the current thread.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live: u64,
+    peak: u64,
+}
+
+impl Counters {
+    fn allocated(&mut self, size: usize) {
+        self.count += 1;
+        self.bytes += size as u64;
+        self.live += size as u64;
+        self.peak = self.peak.max(self.live);
+    }
+
+    /// Memory allocated on another thread or before counting started can be freed here too.
+    fn freed(&mut self, size: usize) {
+        self.live = self.live.saturating_sub(size as u64);
+    }
+}
+
+thread_local! {
+    // Const-initialized without a destructor, so the allocator can use it on any thread at any
+    // time without allocating itself.
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(f: impl FnOnce(&mut Counters)) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let _ = COUNTERS.try_with(|counters| {
+        let mut updated = counters.get();
+        f(&mut updated);
+        counters.set(updated);
+    });
+}
+
+/// The system allocator, counting allocations per thread once [`enable`]d.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(|counters| counters.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(|counters| counters.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(|counters| counters.freed(layout.size()));
+    }
+
+    /// Counts as freeing the old block and allocating the new one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record(|counters| {
+                counters.freed(layout.size());
+                counters.allocated(new_size);
+            });
+        }
+        new
+    }
+}
+
+/// Starts counting allocations. Without [`Counting`] installed there is nothing to count.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// What a piece of code allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Allocations, including reallocations.
+    pub count: u64,
+    /// Bytes allocated in total.
+    pub bytes: u64,
+    /// The most bytes that were live at once, beyond those live before.
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} total, {} peak",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary unit that keeps the number readable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes = self.0 as f64;
+        if self.0 < 1 << 10 {
+            write!(f, "{} B", self.0)
+        } else if self.0 < 1 << 20 {
+            write!(f, "{:.1} KiB", bytes / (1u64 << 10) as f64)
+        } else if self.0 < 1 << 30 {
+            write!(f, "{:.1} MiB", bytes / (1u64 << 20) as f64)
+        } else {
+            write!(f, "{:.1} GiB", bytes / (1u64 << 30) as f64)
+        }
+    }
+}
+
+/// Runs `f` and returns what it allocated on this thread.
+pub fn track<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let before = COUNTERS.with(|counters| {
+        let before = counters.get();
+        counters.set(Counters {
+            peak: before.live,
+            ..before
+        });
+        before
+    });
+    let result = f();
+    let after = COUNTERS.with(|counters| {
+        let after = counters.get();
+        // Keep the peak of an enclosing `track` intact.
+        counters.set(Counters {
+            peak: after.peak.max(before.peak),
+            ..after
+        });
+        after
+    });
+
+    let stats = AllocStats {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+        peak: after.peak - before.live,
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn counts() {
+        enable();
+        let (_, stats) = track(|| {
+            let first = vec![0u8; 100];
+            drop(first);
+            let mut second = Vec::<u8>::with_capacity(60);
+            second.reserve_exact(120);
+            second
+        });
+        assert_eq!(
+            stats,
+            AllocStats {
+                count: 3,
+                bytes: 280,
+                peak: 120
+            }
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+    }
+}
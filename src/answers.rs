@@ -0,0 +1,155 @@
+use std::{collections::HashMap, fmt::Display, io, path::Path};
+
+pub const FILE_NAME: &str = "answers";
+
+/// Known answers for the inputs in one directory.
+///
+/// Each line holds an input file name, a part and the answer, separated by whitespace:
+///
+/// ```text
+/// # input part answer
+/// day5 1 806029445
+/// day5 2 59370572
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: HashMap<(String, u8), String>,
+}
+
+impl Answers {
+    /// Loads an answers file, treating a missing file as having no answers.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, AnswersError> {
+        let mut entries = HashMap::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (Some(input), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(AnswersError::Line(i + 1));
+            };
+            let part = part
+                .parse::<u8>()
+                .ok()
+                .filter(|part| matches!(part, 1 | 2))
+                .ok_or(AnswersError::Line(i + 1))?;
+            entries.insert((input.to_string(), part), answer.trim().to_string());
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn insert(&mut self, input: &str, part: u8, answer: &str) {
+        self.entries
+            .insert((input.to_string(), part), answer.to_string());
+    }
+
+    pub fn get(&self, input: &str, part: u8) -> Option<&str> {
+        self.entries
+            .get(&(input.to_string(), part))
+            .map(String::as_str)
+    }
+
+    /// Compares an answer against the stored one for the same input and part.
+    pub fn check(&self, input: &str, part: u8, answer: &str) -> Verdict {
+        match self.get(input, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The answer differs from the stored one, which is kept for reporting.
+    Fail(String),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail(_) => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        match self {
+            Verdict::Fail(expected) => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    /// A line that isn't `input part answer`.
+    Line(usize),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "could not read answers: {}", err),
+            AnswersError::Line(line) => write!(f, "malformed answers file on line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+
+    const ANSWERS: &str = r#"# input part answer
+day5 1 806029445
+
+day5.ex1 2 46
+day9 1 a b c
+"#;
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check("day5", 1, "806029445"), Verdict::Pass);
+        assert_eq!(
+            answers.check("day5.ex1", 2, "45"),
+            Verdict::Fail("46".to_string())
+        );
+        assert_eq!(answers.check("day5", 2, "1"), Verdict::Unknown);
+        assert_eq!(answers.get("day9", 1), Some("a b c"));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(Answers::parse("day5 1 2\nday5 3 4").is_err());
+        assert!(Answers::parse("day5 1").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Cooperative cancellation for long-running parts.
+//!
+//! The runner installs a [`Token`] on the thread running a part and cancels it when the part
+//! runs out of time. Solutions with long loops call [`check`] every so often to stop early.
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::PuzzleError;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Makes this the current thread's token until the guard is dropped.
+    pub fn install(&self) -> Guard {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        Guard { previous }
+    }
+}
+
+/// Restores the previously installed token when dropped.
+pub struct Guard {
+    previous: Option<Token>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Whether the part running on this thread was cancelled.
+///
+/// Always `false` when no token is installed, e.g. in tests.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled))
+}
+
+/// Fails with [`PuzzleError::Cancelled`] once the part running on this thread was cancelled.
+pub fn check() -> Result<(), PuzzleError> {
+    if is_cancelled() {
+        Err(PuzzleError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn install() {
+        assert_eq!(check(), Ok(()));
+        let token = Token::default();
+        {
+            let _guard = token.install();
+            assert_eq!(check(), Ok(()));
+            token.cancel();
+            assert_eq!(check(), Err(PuzzleError::Cancelled));
+        }
+        assert_eq!(check(), Ok(()));
+    }
+}
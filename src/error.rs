@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use crate::slurp::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    /// A slurp parser rejected the input.
+    Parse(ParseError),
+    /// The input parsed but breaks an assumption the solution relies on.
+    InvalidInput(String),
+    /// The search finished without finding an answer.
+    NoSolution,
+    /// The part hasn't been solved yet.
+    NotImplemented,
+    /// The part stopped early because it was cancelled, e.g. after running out of time.
+    Cancelled,
+}
+
+impl PuzzleError {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::InvalidInput(message.into())
+    }
+}
+
+impl From<ParseError> for PuzzleError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleError::Parse(error) => write!(f, "parse error: {}", error),
+            PuzzleError::InvalidInput(message) => write!(f, "invalid input: {}", message),
+            PuzzleError::NoSolution => write!(f, "no solution found"),
+            PuzzleError::NotImplemented => write!(f, "not implemented"),
+            PuzzleError::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
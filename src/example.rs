@@ -0,0 +1,181 @@
+use std::fmt::Display;
+
+use crate::{answers::Answers, Part, Puzzle, PuzzleError};
+
+/// A worked example from a puzzle description, with its expected answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub part: Part,
+    pub input: &'static str,
+    pub expected: &'static str,
+}
+
+impl Example {
+    /// Solves the example, rendering the answer so it can be compared to `expected`.
+    pub fn solve<T: Puzzle>(&self) -> Result<String, PuzzleError> {
+        T::solve(self.input, self.part)
+    }
+}
+
+/// The result of running one example.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExampleReport {
+    pub example: Example,
+    pub result: Result<String, PuzzleError>,
+}
+
+impl ExampleReport {
+    pub fn passed(&self) -> bool {
+        self.result.as_deref() == Ok(self.example.expected)
+    }
+}
+
+impl Display for ExampleReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (part {}): ",
+            self.example.name,
+            self.example.part.number()
+        )?;
+        match &self.result {
+            Ok(_) if self.passed() => write!(f, "PASS"),
+            Ok(answer) => write!(f, "FAIL ({}, expected {})", answer, self.example.expected),
+            Err(err) => write!(f, "failed - {}", err),
+        }
+    }
+}
+
+/// The expected answers of the examples whose input is `input`, stored under `name`.
+///
+/// This is how `aoc23 --example` knows the answers for an example file: it holds the same input
+/// as one or more of the puzzle's [`Puzzle::EXAMPLES`].
+pub fn answers(examples: &[Example], name: &str, input: &str) -> Answers {
+    let mut answers = Answers::default();
+    for example in examples {
+        if example.input.lines().eq(input.lines()) {
+            answers.insert(name, example.part.number(), example.expected);
+        }
+    }
+    answers
+}
+
+/// Declares a day's examples as a table and generates a `#[test]` for each row, plus one running
+/// the row with CRLF line endings.
+///
+/// The table becomes the module's `EXAMPLES` constant, which the puzzle exposes as
+/// [`Puzzle::EXAMPLES`] so `aoc23 test` can run the same examples, and `aoc23 --example` can
+/// check the example files against their answers:
+///
+/// ```ignore
+/// examples! {
+///     Day2 {
+///         example1: One(EXAMPLE) => 8,
+///         example2: Two(EXAMPLE) => 2286,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($puzzle:ty {
+        $($(#[$attr:meta])* $name:ident: $part:ident($input:expr) => $expected:literal),* $(,)?
+    }) => {
+        pub const EXAMPLES: &[$crate::example::Example] = &[$(
+            $crate::example::Example {
+                name: stringify!($name),
+                part: $crate::Part::$part,
+                input: $input,
+                expected: concat!($expected),
+            }
+        ),*];
+
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                $(#[$attr])*
+                #[test]
+                fn $name() {
+                    let example = EXAMPLES
+                        .iter()
+                        .find(|example| example.name == stringify!($name))
+                        .unwrap();
+                    assert_eq!(
+                        example.solve::<$puzzle>(),
+                        Ok(example.expected.to_string())
+                    );
+                }
+            )*
+
+            /// The same examples with a byte order mark and CRLF line endings.
+            mod crlf {
+                use super::*;
+
+                $(
+                    $(#[$attr])*
+                    #[test]
+                    fn $name() {
+                        let example = EXAMPLES
+                            .iter()
+                            .find(|example| example.name == stringify!($name))
+                            .unwrap();
+                        let input = format!("\u{feff}{}", example.input.replace('\n', "\r\n"));
+                        assert_eq!(
+                            <$puzzle as $crate::Puzzle>::solve(&input, example.part),
+                            Ok(example.expected.to_string())
+                        );
+                    }
+                )*
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() {
+        let example = Example {
+            name: "example1",
+            part: Part::One,
+            input: "",
+            expected: "8",
+        };
+        let report = |result| ExampleReport { example, result }.to_string();
+        assert_eq!(report(Ok("8".to_string())), "example1 (part 1): PASS");
+        assert_eq!(
+            report(Ok("9".to_string())),
+            "example1 (part 1): FAIL (9, expected 8)"
+        );
+        assert_eq!(
+            report(Err(PuzzleError::NoSolution)),
+            "example1 (part 1): failed - no solution found"
+        );
+    }
+
+    #[test]
+    fn example_answers() {
+        let examples = [
+            Example {
+                name: "example1",
+                part: Part::One,
+                input: "1\n2\n",
+                expected: "3",
+            },
+            Example {
+                name: "example2",
+                part: Part::Two,
+                input: "4\n",
+                expected: "4",
+            },
+        ];
+        let found = answers(&examples, "day1.ex1", "1\r\n2");
+        assert_eq!(found.get("day1.ex1", 1), Some("3"));
+        assert_eq!(found.get("day1.ex1", 2), None);
+        assert_eq!(answers(&examples, "day1.ex2", "5\n"), Answers::default());
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use clap::ValueEnum;
+
+use crate::{
+    report::{Comparison, DayReport, Outcome, PartReport},
+    DEFAULT_VARIANT,
+};
+
+pub const DEFAULT_FILE: &str = "aoc23-history";
+
+/// The year of the `dayN` ids recorded before solutions were namespaced by year.
+const LEGACY_YEAR: u16 = 2023;
+
+/// One timed part from an earlier run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// When the run started, in seconds since the Unix epoch.
+    pub run: u64,
+    pub id: String,
+    pub part: u8,
+    pub time: Duration,
+    /// How the answer compared to the stored one, as a [`Verdict::name`](crate::answers::Verdict::name).
+    pub verdict: Option<String>,
+}
+
+/// Per-part timings of every recorded run, oldest first.
+///
+/// Each line holds the run, the solution identifier, the part, its time in nanoseconds and
+/// optionally the verdict on its answer:
+///
+/// ```text
+/// # run id part nanos [verdict]
+/// 1702000000 2023/day7 1 412000 pass
+/// ```
+///
+/// Ids without a year, like `day7`, are from before there were other years and are read as
+/// 2023's.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+/// What the current timings are compared against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Baseline {
+    /// The most recent recorded time.
+    #[default]
+    Previous,
+    /// The fastest recorded time.
+    Best,
+}
+
+impl History {
+    /// Loads a history file, treating a missing file as an empty history.
+    pub fn load(path: &Path) -> Result<Self, HistoryError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(HistoryError::Io(err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, HistoryError> {
+        let mut entries = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let (run, id, part, nanos, verdict) = match fields[..] {
+                [run, id, part, nanos] => (run, id, part, nanos, None),
+                [run, id, part, nanos, verdict] => (run, id, part, nanos, Some(verdict)),
+                _ => return Err(HistoryError::Line(i + 1)),
+            };
+            let (Ok(run), Ok(part), Ok(nanos)) = (run.parse(), part.parse(), nanos.parse::<u64>())
+            else {
+                return Err(HistoryError::Line(i + 1));
+            };
+            entries.push(Entry {
+                run,
+                id: if id.contains('/') {
+                    id.to_string()
+                } else {
+                    format!("{}/{}", LEGACY_YEAR, id)
+                },
+                part,
+                time: Duration::from_nanos(nanos),
+                verdict: verdict.map(str::to_string),
+            });
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn baseline(&self, baseline: Baseline, id: &str, part: u8) -> Option<Duration> {
+        let mut times = self
+            .entries
+            .iter()
+            .filter(|entry| entry.id == id && entry.part == part)
+            .map(|entry| entry.time);
+        match baseline {
+            Baseline::Previous => times.next_back(),
+            Baseline::Best => times.min(),
+        }
+    }
+
+    /// The entries of the most recent run that recorded the solution.
+    pub fn last_run(&self, id: &str) -> Vec<&Entry> {
+        let Some(last) = self.entries.iter().rfind(|entry| entry.id == id) else {
+            return Vec::new();
+        };
+        self.entries
+            .iter()
+            .filter(|entry| entry.id == id && entry.run == last.run)
+            .collect()
+    }
+
+    /// Compares every solved part of a report against its baseline.
+    ///
+    /// A part regressed when it is more than `threshold` percent slower than the baseline.
+    pub fn compare(&self, id: &str, report: &mut DayReport, baseline: Baseline, threshold: f64) {
+        for part in &mut report.parts {
+            if !matches!(part.outcome, Outcome::Solved(_)) {
+                continue;
+            }
+            if let Some(time) = self.baseline(baseline, &key(id, part), part.part.number()) {
+                let change = Comparison::change(part.time, time);
+                part.comparison = Some(Comparison {
+                    baseline: time,
+                    change,
+                    regressed: change > threshold,
+                });
+            }
+        }
+    }
+}
+
+/// The identifier timings of a part are recorded under, which names the variant unless it's
+/// the default one.
+fn key(id: &str, part: &PartReport) -> String {
+    if part.variant == DEFAULT_VARIANT {
+        id.to_string()
+    } else {
+        format!("{}#{}", id, part.variant)
+    }
+}
+
+/// The entries recording the solved parts of a report.
+pub fn entries(run: u64, id: &str, report: &DayReport) -> Vec<Entry> {
+    report
+        .parts
+        .iter()
+        .filter(|part| matches!(part.outcome, Outcome::Solved(_)))
+        .map(|part| Entry {
+            run,
+            id: key(id, part),
+            part: part.part.number(),
+            time: part.time,
+            verdict: part
+                .verdict
+                .as_ref()
+                .map(|verdict| verdict.name().to_string()),
+        })
+        .collect()
+}
+
+/// Appends entries to a history file, creating it if needed.
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let mut lines = String::new();
+    for entry in entries {
+        lines.push_str(&format!(
+            "{} {} {} {}",
+            entry.run,
+            entry.id,
+            entry.part,
+            entry.time.as_nanos()
+        ));
+        if let Some(verdict) = &entry.verdict {
+            lines.push_str(&format!(" {}", verdict));
+        }
+        lines.push('\n');
+    }
+    file.write_all(lines.as_bytes())
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(io::Error),
+    /// A line that isn't `run id part nanos [verdict]`.
+    Line(usize),
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io(err) => write!(f, "could not read timing history: {}", err),
+            HistoryError::Line(line) => write!(f, "malformed timing history on line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Part;
+
+    const HISTORY: &str = r#"# run id part nanos [verdict]
+1 day7 1 400
+1 day7 2 900
+2 day7 1 300 pass
+2 day8 1 300 fail
+
+3 2023/day7 1 500 pass
+3 2023/day7 2 800 unknown
+"#;
+
+    #[test]
+    fn baseline() {
+        let history = History::parse(HISTORY).unwrap();
+        let previous = history.baseline(Baseline::Previous, "2023/day7", 1);
+        assert_eq!(previous, Some(Duration::from_nanos(500)));
+        let best = history.baseline(Baseline::Best, "2023/day7", 1);
+        assert_eq!(best, Some(Duration::from_nanos(300)));
+        assert_eq!(history.baseline(Baseline::Best, "2023/day9", 1), None);
+        assert!(History::parse("1 day7 1").is_err());
+        assert!(History::parse("1 day7 1 500 pass x").is_err());
+        assert!(History::parse("1 day7 x 500").is_err());
+    }
+
+    #[test]
+    fn compare() {
+        let history = History::parse(HISTORY).unwrap();
+        let mut report = DayReport {
+            day: 7,
+            input_set: None,
+            parse_time: Duration::ZERO,
+            parse_stats: None,
+            parse_spans: Vec::new(),
+            parts: vec![
+                PartReport::solved(Part::One, 1, Duration::from_nanos(540)),
+                PartReport::solved(Part::Two, 2, Duration::from_nanos(1000)),
+            ],
+        };
+        history.compare("2023/day7", &mut report, Baseline::Previous, 10.0);
+
+        let comparison = report.parts[0].comparison.clone().unwrap();
+        assert_eq!(comparison.baseline, Duration::from_nanos(500));
+        assert!(!comparison.regressed);
+        assert!(report.parts[1].comparison.clone().unwrap().regressed);
+
+        let last = history.last_run("2023/day7");
+        assert_eq!(last.len(), 2);
+        assert_eq!(last[1].verdict.as_deref(), Some("unknown"));
+        assert_eq!(history.last_run("2023/day8")[0].run, 2);
+
+        report.parts[1].variant = "fast".to_string();
+        let entries = entries(4, "2023/day7", &report);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].id, "2023/day7#fast");
+        assert_eq!(entries[1].time, Duration::from_nanos(1000));
+    }
+}
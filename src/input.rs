@@ -0,0 +1,265 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::answers;
+
+pub const DEFAULT_DIR: &str = "src/input";
+
+/// Where puzzle inputs are read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A directory holding a directory per year, each with one `dayN` file per day.
+    Dir(PathBuf),
+    /// A single file, used for whichever day is run.
+    File(PathBuf),
+    Stdin,
+    /// The numbered example of each day in a directory laid out like [`Source::Dir`], stored
+    /// as `dayN.exK` next to the day's input.
+    Example(PathBuf, u8),
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Self::Dir(PathBuf::from(DEFAULT_DIR))
+    }
+}
+
+impl Source {
+    /// Parses an `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    /// The inputs of a named set in `dir`, laid out like `dir` itself.
+    pub fn set(dir: &Path, name: &str) -> Self {
+        Self::Dir(dir.join(name))
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Dir(dir) => Some(dir.join(year.to_string()).join(format!("day{}", day))),
+            Self::Example(dir, number) => Some(
+                dir.join(year.to_string())
+                    .join(format!("day{}.ex{}", day, number)),
+            ),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+
+    /// The answers file covering a day's input, and the name its answers are stored under.
+    pub fn answers(&self, year: u16, day: u8) -> Option<(PathBuf, String)> {
+        let path = self.path(year, day)?;
+        let name = path.file_name()?.to_str()?.to_string();
+        let dir = path.parent().unwrap_or(Path::new(""));
+        Some((dir.join(answers::FILE_NAME), name))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let result = match self.path(year, day) {
+            Some(path) => std::fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|error| InputError {
+            day,
+            path: self.path(year, day),
+            error,
+        })
+    }
+}
+
+/// The names of the input sets in `dir` that have inputs for `year`, sorted.
+///
+/// A set is a subdirectory mirroring the layout of `dir`, e.g. `src/input/alice/2023/day5`, with
+/// its own answers file next to its inputs.
+pub fn sets(dir: &Path, year: u16) -> io::Result<Vec<String>> {
+    let mut sets = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.join(year.to_string()).is_dir() {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            sets.push(name.to_string());
+        }
+    }
+    sets.sort();
+    Ok(sets)
+}
+
+/// How much the runner cleans up an input before a puzzle parses it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Normalize {
+    /// Hand the input over untouched.
+    Raw,
+    /// Strip a byte order mark and turn CRLF line endings into LF.
+    LineEndings,
+    /// Also drop trailing blank lines, including ones holding only whitespace, so the input ends
+    /// with exactly one newline.
+    #[default]
+    Full,
+}
+
+/// Cleans up `input` as far as `normalize` asks, borrowing it when nothing changes.
+pub fn normalize(input: &str, normalize: Normalize) -> Cow<'_, str> {
+    if normalize == Normalize::Raw {
+        return Cow::Borrowed(input);
+    }
+
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut input = match input.contains('\r') {
+        true => Cow::Owned(input.replace("\r\n", "\n")),
+        false => Cow::Borrowed(input),
+    };
+    if normalize == Normalize::Full {
+        let trimmed = trim_blank_lines(&input);
+        if trimmed.len() + 1 != input.len() || !input.ends_with('\n') {
+            input = Cow::Owned(format!("{}\n", trimmed));
+        }
+    }
+    input
+}
+
+/// Strips the trailing newlines and the blank lines before them, keeping the whitespace at the
+/// end of the last line with content.
+fn trim_blank_lines(input: &str) -> &str {
+    let mut trimmed = input.trim_end_matches('\n');
+    while let Some(last) = trimmed
+        .rsplit('\n')
+        .next()
+        .filter(|line| !line.is_empty() && line.trim().is_empty())
+    {
+        trimmed = trimmed[..trimmed.len() - last.len()].trim_end_matches('\n');
+    }
+    trimmed
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub path: Option<PathBuf>,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(
+                f,
+                "could not read input for day {} from {}: {}",
+                self.day,
+                path.display(),
+                self.error
+            ),
+            None => write!(
+                f,
+                "could not read input for day {} from stdin: {}",
+                self.day, self.error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{normalize, sets, Normalize, Source};
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            Source::default().path(2023, 5),
+            Some(PathBuf::from("src/input/2023/day5"))
+        );
+        assert_eq!(
+            Source::from_arg("my/input").path(2023, 5),
+            Some(PathBuf::from("my/input"))
+        );
+        assert_eq!(Source::from_arg("-").path(2023, 5), None);
+        assert_eq!(
+            Source::Example(PathBuf::from("src/input"), 2).path(2023, 5),
+            Some(PathBuf::from("src/input/2023/day5.ex2"))
+        );
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(
+            Source::default().answers(2022, 5),
+            Some((PathBuf::from("src/input/2022/answers"), "day5".to_string()))
+        );
+        assert_eq!(
+            Source::from_arg("src/input/2023/day5.ex1").answers(2023, 5),
+            Some((
+                PathBuf::from("src/input/2023/answers"),
+                "day5.ex1".to_string()
+            ))
+        );
+        assert_eq!(
+            Source::Example(PathBuf::from("src/input"), 1).answers(2023, 5),
+            Some((
+                PathBuf::from("src/input/2023/answers"),
+                "day5.ex1".to_string()
+            ))
+        );
+        assert_eq!(Source::Stdin.answers(2023, 5), None);
+    }
+
+    #[test]
+    fn input_sets() {
+        let dir = std::env::temp_dir().join(format!("aoc23-sets-{}", std::process::id()));
+        for path in ["2023", "bob/2023", "alice/2023", "carol/2022"] {
+            std::fs::create_dir_all(dir.join(path)).unwrap();
+        }
+        std::fs::write(dir.join("alice/2023/day5"), "").unwrap();
+
+        let found = sets(&dir, 2023);
+        let source = Source::set(&dir, "alice");
+        let path = source.path(2023, 5);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found.unwrap(), ["alice", "bob"]);
+        assert_eq!(path, Some(dir.join("alice/2023/day5")));
+        assert_eq!(
+            source.answers(2023, 5),
+            Some((dir.join("alice/2023/answers"), "day5".to_string()))
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let error = Source::Dir(PathBuf::from("does/not/exist"))
+            .load(2023, 3)
+            .unwrap_err();
+        assert_eq!(error.error.kind(), std::io::ErrorKind::NotFound);
+        assert!(error.to_string().contains("does/not/exist/2023/day3"));
+    }
+
+    #[test]
+    fn normalizes() {
+        let input = "\u{feff}1 2\r\n3 4\r\n\r\n  \n";
+        assert_eq!(normalize(input, Normalize::Raw), input);
+        assert_eq!(normalize(input, Normalize::LineEndings), "1 2\n3 4\n\n  \n");
+        assert_eq!(normalize(input, Normalize::Full), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\n3 4", Normalize::Full), "1 2\n3 4\n");
+        assert_eq!(normalize("a  \n\t\n", Normalize::Full), "a  \n");
+        assert_eq!(normalize(" \n\n", Normalize::Full), "\n");
+        assert!(matches!(
+            normalize("1 2\n3 4\n", Normalize::Full),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+//! Panic isolation, so a broken day fails on its own instead of aborting the whole run.
+//!
+//! [`catch`] runs a closure under `catch_unwind`. While it runs, a panic hook records where the
+//! panic happened instead of printing it, and the panic is returned as a [`Panic`] to report.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Wraps the current panic hook, which still handles panics outside of [`catch`].
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.with(|current| *current.borrow_mut() = location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// A caught panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Where the panic happened, as `file:line:column`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown payload".to_string()
+    }
+}
+
+/// Runs `f`, returning its panic instead of unwinding further.
+///
+/// Whatever `f` was working on is dropped with the panic, so it can't be seen half-updated.
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    install_hook();
+    let catching = CATCHING.with(|current| current.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|current| current.set(catching));
+
+    result.map_err(|payload| Panic {
+        message: message(&*payload),
+        location: LOCATION.with(|location| location.borrow_mut().take()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches() {
+        assert_eq!(catch(|| 5), Ok(5));
+
+        let line = line!() + 1;
+        let panic = catch(|| panic!("Invalid tile: {:?}", 'x')).unwrap_err();
+        assert_eq!(panic.message, "Invalid tile: 'x'");
+        let location = panic.location.clone().unwrap();
+        assert!(location.starts_with(&format!("{}:{}:", file!(), line)));
+        assert_eq!(
+            panic.to_string(),
+            format!("panicked at {}: Invalid tile: 'x'", location)
+        );
+    }
+}
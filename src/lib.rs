@@ -1,39 +1,587 @@
-pub mod day1;
-pub mod day10;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod allocation;
+pub mod answers;
+pub mod cancel;
+mod error;
+pub mod example;
+pub mod history;
+pub mod input;
+pub mod isolate;
+pub mod progress;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
 pub mod slurp;
+pub mod span;
+pub mod timing;
+pub mod validate;
+pub mod y2023;
 
-use std::{fmt::Display, time::Instant};
+pub use error::PuzzleError;
+
+use std::{
+    fmt::Display,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use example::Example;
+use input::Normalize;
+use report::{DayReport, Outcome, PartReport};
+use timing::Stats;
+use validate::Validator;
 
 pub trait Puzzle {
-    type Output: Display;
+    const INFO: Info;
+
+    /// The parsed input, shared by both parts.
+    ///
+    /// When the parts read the input differently, `parse` keeps everything either of them needs.
+    /// It is shared with the worker threads that run the parts under a timeout.
+    type Parsed: Send + Sync + 'static;
+    /// The answer to part 1. The runner and the stored answers work with how it's displayed.
+    type Output1: Display + Send + 'static;
+    /// The answer to part 2, which doesn't have to be the same kind of value as part 1's.
+    type Output2: Display + Send + 'static;
+
+    /// The worked examples of the puzzle, usually declared with [`examples!`].
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Other implementations of part 1, run alongside or instead of `part1`.
+    const PART1_VARIANTS: &'static [Variant<Self::Parsed, Self::Output1>] = &[];
+    /// Other implementations of part 2, run alongside or instead of `part2`.
+    const PART2_VARIANTS: &'static [Variant<Self::Parsed, Self::Output2>] = &[];
 
-    fn part1(input: &str) -> Self::Output;
-    fn part2(input: &str) -> Self::Output;
+    /// How much the input is cleaned up before `parse` sees it.
+    ///
+    /// Puzzles where whitespace at the end of the input matters can ask for less.
+    const NORMALIZE: Normalize = Normalize::Full;
+
+    /// Checks the assumptions `parse` and the parts make about the input, if the puzzle has a
+    /// checker. It is given the normalized input.
+    const VALIDATOR: Option<Validator> = None;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError>;
+    fn part1(input: &Self::Parsed) -> Result<Self::Output1, PuzzleError>;
+    fn part2(input: &Self::Parsed) -> Result<Self::Output2, PuzzleError>;
+
+    /// Parses the input and solves one part of it, returning the displayed answer.
+    fn solve(input: &str, part: Part) -> Result<String, PuzzleError> {
+        let parsed = Self::parse(&input::normalize(input, Self::NORMALIZE))?;
+        match part {
+            Part::One => Self::part1(&parsed).map(|answer| answer.to_string()),
+            Part::Two => Self::part2(&parsed).map(|answer| answer.to_string()),
+        }
+    }
 }
 
-pub fn run<T: Puzzle>(day: u8, input: &str) {
-    println!("Day {}:", day);
+/// The name `part1` and `part2` are known by among the variants of a part.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// A named alternative implementation of one part, e.g. a naive one kept next to a fast one.
+pub struct Variant<P, O> {
+    pub name: &'static str,
+    pub solve: fn(&P) -> Result<O, PuzzleError>,
+}
+
+type Solver<P, O> = fn(&P) -> Result<O, PuzzleError>;
+
+/// The default implementation of a part followed by its other variants.
+fn variants<P, O>(
+    default: Solver<P, O>,
+    others: &[Variant<P, O>],
+) -> Vec<(&'static str, Solver<P, O>)> {
+    let others = others.iter().map(|variant| (variant.name, variant.solve));
+    std::iter::once((DEFAULT_VARIANT, default))
+        .chain(others)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// What the runner knows about a puzzle besides its code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Info {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The parts that have been solved, which is none of them for a freshly scaffolded day.
+    pub parts: &'static [Part],
+}
+
+impl Info {
+    pub fn implements(&self, part: Part) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+/// Which implementations of each part to run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Variants {
+    /// Only `part1` and `part2`.
+    #[default]
+    Default,
+    /// The variant with this name, or the default one for parts that don't have it.
+    Named(String),
+    /// Every variant, cross-checking their answers.
+    All,
+}
+
+/// How the runner should execute the selected puzzles.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Only run this part, or both when `None`.
+    pub part: Option<Part>,
+    /// Time parsing and each part over this many runs instead of once.
+    pub bench: Option<u32>,
+    /// Untimed runs before benchmarking, to warm up caches and the branch predictor.
+    pub warmup: u32,
+    /// Abandon a part that runs longer than this, including all of its benchmark runs.
+    pub timeout: Option<Duration>,
+    /// Where parts report their progress, if anywhere.
+    pub progress: Option<Arc<dyn progress::Sink>>,
+    pub variants: Variants,
+    /// Count the allocations of each part, which needs [`allocation::Counting`] installed as
+    /// the global allocator and enabled.
+    pub alloc_stats: bool,
+}
+
+impl Options {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+struct Measured<O> {
+    result: Result<O, PuzzleError>,
+    /// The time of a single run, or the median when benchmarking.
+    time: Duration,
+    stats: Option<Stats>,
+}
+
+/// Runs `f` once, or benchmarks it when `options.bench` is set, keeping the last result.
+fn measure<O>(options: &Options, mut f: impl FnMut() -> Result<O, PuzzleError>) -> Measured<O> {
+    let Some(runs) = options.bench else {
+        let start = Instant::now();
+        let result = f();
+        let time = start.elapsed();
+        return Measured {
+            result,
+            time,
+            stats: None,
+        };
+    };
+
+    for _ in 0..options.warmup {
+        if let Err(err) = f() {
+            return Measured {
+                result: Err(err),
+                time: Duration::ZERO,
+                stats: None,
+            };
+        }
+    }
+
+    let mut last = None;
+    let mut samples = Vec::with_capacity(runs as usize);
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let result = f();
+        let time = start.elapsed();
+        samples.push(time);
+        if result.is_err() {
+            return Measured {
+                result,
+                time,
+                stats: None,
+            };
+        }
+        last = Some(result);
+    }
+
+    let stats = Stats::from_samples(&samples).expect("at least one run");
+    Measured {
+        result: last.expect("at least one run"),
+        time: stats.median,
+        stats: Some(stats),
+    }
+}
+
+fn measure_part<P, O: Display>(
+    day: u8,
+    part: Part,
+    solve: Solver<P, O>,
+    parsed: &P,
+    options: &Options,
+) -> PartReport {
+    let _progress = options
+        .progress
+        .clone()
+        .map(|sink| progress::attach(sink, day, part));
+    let (mut allocs, mut spans) = (None, Vec::new());
+    let measured = isolate::catch(|| {
+        measure(options, || {
+            let (result, collected) = span::collect(|| {
+                if !options.alloc_stats {
+                    return solve(parsed);
+                }
+                let (result, stats) = allocation::track(|| solve(parsed));
+                allocs = Some(stats);
+                result
+            });
+            spans = collected;
+            result
+        })
+    });
+    let measured = match measured {
+        Ok(measured) => measured,
+        Err(panic) => return PartReport::failed(part, panic),
+    };
+
+    let report = match measured.result {
+        Ok(answer) => PartReport::solved(part, answer, measured.time),
+        Err(err) => PartReport::failed(part, err),
+    };
+    PartReport {
+        time: measured.time,
+        stats: measured.stats,
+        allocs,
+        spans,
+        ..report
+    }
+}
+
+/// Runs a part, on a worker thread when there is a timeout.
+///
+/// A part that runs out of time has its cancellation token cancelled and is reported as timed
+/// out right away. Its thread is left to stop at its next [`cancel::check`], or to finish.
+fn run_part<P: Send + Sync + 'static, O: Display + Send + 'static>(
+    day: u8,
+    part: Part,
+    solve: Solver<P, O>,
+    parsed: &Arc<P>,
+    options: &Options,
+) -> PartReport {
+    let Some(timeout) = options.timeout else {
+        return measure_part(day, part, solve, parsed, options);
+    };
+
+    let token = cancel::Token::default();
+    let (sender, receiver) = mpsc::channel();
+    let worker = {
+        let (parsed, options, token) = (Arc::clone(parsed), options.clone(), token.clone());
+        move || {
+            let _guard = token.install();
+            // The receiver is gone when the part timed out, and then nobody needs the report.
+            let _ = sender.send(measure_part(day, part, solve, &parsed, &options));
+        }
+    };
+    if let Err(err) = thread::Builder::new()
+        .name(format!("part{}", part.number()))
+        .spawn(worker)
+    {
+        return PartReport::failed(part, format!("could not start worker thread: {}", err));
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(report) => report,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            PartReport::timed_out(part, timeout)
+        }
+        Err(RecvTimeoutError::Disconnected) => PartReport::failed(part, "worker thread panicked"),
+    }
+}
+
+/// Runs the selected variants of a part.
+///
+/// When several variants solve the part, every answer that differs from the first one is turned
+/// into a failure.
+fn run_variants<P: Send + Sync + 'static, O: Display + Send + 'static>(
+    day: u8,
+    part: Part,
+    default: Solver<P, O>,
+    others: &[Variant<P, O>],
+    parsed: &Arc<P>,
+    options: &Options,
+) -> Vec<PartReport> {
+    let mut variants = variants(default, others);
+    match &options.variants {
+        Variants::Named(name) if variants.iter().any(|(variant, _)| variant == name) => {
+            variants.retain(|(variant, _)| variant == name);
+        }
+        Variants::All => {}
+        _ => variants.truncate(1),
+    }
+
+    let mut reports = variants
+        .into_iter()
+        .map(|(name, solve)| PartReport {
+            variant: name.to_string(),
+            ..run_part(day, part, solve, parsed, options)
+        })
+        .collect::<Vec<_>>();
+
+    let reference = reports
+        .iter()
+        .find_map(|report| Some((report.variant.clone(), report.outcome.answer()?.to_string())));
+    if let Some((name, expected)) = reference {
+        for report in &mut reports {
+            if let Some(answer) = report.outcome.answer().filter(|&answer| answer != expected) {
+                report.outcome = Outcome::Failed(format!(
+                    "answer {} disagrees with {} variant's {}",
+                    answer, name, expected
+                ));
+            }
+        }
+    }
+    reports
+}
+
+/// Normalizes and parses the input and runs the selected parts of a puzzle, once or as a
+/// benchmark.
+///
+/// When benchmarking, the reported times are the medians of their runs. A panic in parsing or in
+/// a part fails what panicked, without unwinding out of `run`.
+///
+/// Parts missing from [`Info::parts`] are reported as not implemented instead of being run.
+pub fn run<T: Puzzle>(input: &str, options: &Options) -> DayReport {
+    let day = T::INFO.day;
+    let input = input::normalize(input, T::NORMALIZE);
+    let mut parse_spans = Vec::new();
+    let parsed = isolate::catch(|| {
+        measure(options, || {
+            let (result, spans) = span::collect(|| T::parse(&input).map(Arc::new));
+            parse_spans = spans;
+            result
+        })
+    });
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(panic) => return DayReport::not_run(day, &options.parts(), format!("parse {}", panic)),
+    };
+    let parts = options
+        .parts()
+        .into_iter()
+        .flat_map(|part| match (&parsed.result, part) {
+            _ if !T::INFO.implements(part) => vec![PartReport::not_implemented(part)],
+            (Ok(parsed), Part::One) => {
+                run_variants(day, part, T::part1, T::PART1_VARIANTS, parsed, options)
+            }
+            (Ok(parsed), Part::Two) => {
+                run_variants(day, part, T::part2, T::PART2_VARIANTS, parsed, options)
+            }
+            (Err(err), _) => vec![PartReport::failed(part, err)],
+        })
+        .collect();
+
+    DayReport {
+        day,
+        input_set: None,
+        parse_time: parsed.time,
+        parse_stats: parsed.stats,
+        parse_spans,
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Part 1 answers right away, part 2 spins until it is cancelled.
+    struct Spin;
+
+    impl Puzzle for Spin {
+        const INFO: Info = Info {
+            year: 2023,
+            day: 1,
+            title: "Spin",
+            parts: &[Part::One, Part::Two],
+        };
+        type Parsed = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(_input: &str) -> Result<Self::Parsed, PuzzleError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+            Ok(1)
+        }
+
+        fn part2(_: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+            loop {
+                cancel::check()?;
+                thread::yield_now();
+            }
+        }
+    }
+
+    #[test]
+    fn timeout() {
+        let options = Options {
+            timeout: Some(Duration::from_millis(50)),
+            ..Options::default()
+        };
+        let report = run::<Spin>("", &options);
+        assert_eq!(report.parts[0].outcome, Outcome::Solved("1".to_string()));
+        assert_eq!(report.parts[1].outcome, Outcome::TimedOut);
+        assert_eq!(report.parts[1].time, Duration::from_millis(50));
+    }
+
+    /// Answers part 1 with a number and part 2 with text, which a variant disagrees with.
+    struct Mixed;
+
+    impl Puzzle for Mixed {
+        const INFO: Info = Info {
+            year: 2023,
+            day: 3,
+            title: "Mixed",
+            parts: &[Part::One, Part::Two],
+        };
+        type Parsed = ();
+        type Output1 = u64;
+        type Output2 = String;
+
+        const PART2_VARIANTS: &'static [Variant<Self::Parsed, Self::Output2>] = &[Variant {
+            name: "shouting",
+            solve: |_| Ok("ABC".to_string()),
+        }];
+
+        fn parse(_input: &str) -> Result<Self::Parsed, PuzzleError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+            Ok(42)
+        }
+
+        fn part2(_: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+            Ok("abc".to_string())
+        }
+    }
+
+    #[test]
+    fn outputs() {
+        assert_eq!(Mixed::solve("", Part::One), Ok("42".to_string()));
+        assert_eq!(Mixed::solve("", Part::Two), Ok("abc".to_string()));
+
+        let options = Options {
+            variants: Variants::All,
+            ..Options::default()
+        };
+        let report = run::<Mixed>("", &options);
+        assert_eq!(report.parts[0].outcome, Outcome::Solved("42".to_string()));
+        assert_eq!(report.parts[1].outcome, Outcome::Solved("abc".to_string()));
+        assert_eq!(report.parts[2].variant, "shouting");
+        assert_eq!(
+            report.parts[2].outcome.error(),
+            Some("answer ABC disagrees with default variant's abc")
+        );
+    }
+
+    /// Only part 1 is listed as implemented.
+    struct Unfinished;
+
+    impl Puzzle for Unfinished {
+        const INFO: Info = Info {
+            year: 2023,
+            day: 4,
+            title: "Unfinished",
+            parts: &[Part::One],
+        };
+        type Parsed = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(_input: &str) -> Result<Self::Parsed, PuzzleError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+            Ok(1)
+        }
+
+        fn part2(_: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+            Err(PuzzleError::NotImplemented)
+        }
+    }
+
+    #[test]
+    fn unimplemented_parts() {
+        let report = run::<Unfinished>("", &Options::default());
+        assert_eq!(report.parts[0].outcome, Outcome::Solved("1".to_string()));
+        assert_eq!(report.parts[1].outcome, Outcome::NotImplemented);
+        assert!(!report.failed());
+    }
+
+    /// Panics while parsing `"panic"`, and part 2 always panics.
+    struct Broken;
+
+    impl Puzzle for Broken {
+        const INFO: Info = Info {
+            year: 2023,
+            day: 2,
+            title: "Broken",
+            parts: &[Part::One, Part::Two],
+        };
+        type Parsed = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+            assert_ne!(input.trim(), "panic", "Invalid input");
+            Ok(())
+        }
 
-    let start1 = Instant::now();
-    let answer1 = T::part1(input);
-    let time1 = start1.elapsed();
+        fn part1(_: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+            Ok(1)
+        }
 
-    let start2 = Instant::now();
-    let answer2 = T::part2(input);
-    let time2 = start2.elapsed();
+        fn part2(_: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+            unreachable!()
+        }
+    }
 
-    let total = time1 + time2;
+    #[test]
+    fn panics() {
+        let report = run::<Broken>("", &Options::default());
+        assert_eq!(report.parts[0].outcome, Outcome::Solved("1".to_string()));
+        let error = report.parts[1].outcome.error().unwrap();
+        assert!(error.starts_with("panicked at src/lib.rs:"), "{}", error);
+        assert!(error.ends_with("internal error: entered unreachable code"));
 
-    println!("  Part 1: {} - {}s", answer1, time1.as_secs_f64());
-    println!("  Part 2: {} - {}s", answer2, time2.as_secs_f64());
-    println!("  Total: {}s", total.as_secs_f64());
-    println!();
+        let report = run::<Broken>("panic", &Options::default());
+        for part in &report.parts {
+            let error = part.outcome.error().unwrap();
+            assert!(
+                error.starts_with("parse panicked at src/lib.rs:"),
+                "{}",
+                error
+            );
+        }
+    }
 }
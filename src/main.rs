@@ -1,25 +1,630 @@
+use std::{
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
 use aoc23::{
-    day1::Day1, day10::Day10, day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6,
-    day7::Day7, day8::Day8, day9::Day9, run,
+    allocation::{self, Counting},
+    answers::Answers,
+    example,
+    history::{self, Baseline, History},
+    input::{self, Source},
+    progress::{self, Terminal},
+    registry::{self, Selection, Solution, SOLUTIONS},
+    report::{DayReport, Format, Reporter},
+    scaffold, Options, Part, Variants,
+};
+use clap::{
+    error::ErrorKind, parser::ValueSource, CommandFactory, FromArgMatches, Parser, Subcommand,
 };
-use clap::Parser;
+use rayon::prelude::*;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
 
 #[derive(Parser)]
 struct Args {
-    day: Option<usize>,
-}
-
-fn main() {
-    let _args = Args::parse();
-
-    run::<Day1>(1, include_str!("input/day1"));
-    run::<Day2>(2, include_str!("input/day2"));
-    run::<Day3>(3, include_str!("input/day3"));
-    run::<Day4>(4, include_str!("input/day4"));
-    run::<Day5>(5, include_str!("input/day5"));
-    run::<Day6>(6, include_str!("input/day6"));
-    run::<Day7>(7, include_str!("input/day7"));
-    run::<Day8>(8, include_str!("input/day8"));
-    run::<Day9>(9, include_str!("input/day9"));
-    run::<Day10>(10, include_str!("input/day10"));
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run: a single day (`7`), an inclusive range (`3..8`) or a list (`1,5,9`).
+    /// Runs every registered day of the year when omitted.
+    days: Option<Selection>,
+
+    /// The year whose days are selected, defaulting to the most recent one with solutions.
+    #[arg(long, global = true)]
+    year: Option<u16>,
+
+    /// Only run this part.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Directory holding a `YEAR/dayN` input file per day.
+    #[arg(long, default_value = input::DEFAULT_DIR)]
+    input_dir: PathBuf,
+
+    /// Read the input of a single day from this file, or from stdin when `-`.
+    #[arg(long, conflicts_with = "input_dir")]
+    input: Option<String>,
+
+    /// Read inputs from the named input set, `INPUT_DIR/NAME/YEAR/dayN`, instead of the default
+    /// ones.
+    #[arg(long, value_name = "NAME", conflicts_with = "input")]
+    input_set: Option<String>,
+
+    /// Run the numbered example of each day, `dayN.exN` next to its input, instead of the input.
+    /// Answers are checked against the day's examples with the same input.
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        value_parser = clap::value_parser!(u8).range(1..),
+        conflicts_with = "input"
+    )]
+    example: Option<u8>,
+
+    /// Run the default inputs and every input set that has one for a day, verifying each against
+    /// the answers stored with it.
+    #[arg(long, conflicts_with_all = ["input", "input_set", "example"])]
+    all_sets: bool,
+
+    /// Benchmark each part over N runs and report min, median, mean and standard deviation.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Untimed warm-up runs per part before benchmarking.
+    #[arg(long, default_value_t = 3, requires = "bench")]
+    warmup: u32,
+
+    /// How results are printed.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Fail when any answer differs from the one stored in the input directory's answers file.
+    #[arg(long)]
+    verify: bool,
+
+    /// Solve days in parallel, still printing them in day order. There is no progress line, as
+    /// the days would overwrite each other's.
+    #[arg(long, conflicts_with = "bench")]
+    parallel: bool,
+
+    /// Give up on a part after this many seconds, reporting it as timed out.
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Run this implementation of each part that has one instead of the default one.
+    #[arg(long, value_name = "NAME", conflicts_with = "all_variants")]
+    variant: Option<String>,
+
+    /// Run every implementation of each part, failing when their answers disagree.
+    #[arg(long)]
+    all_variants: bool,
+
+    /// Report how many allocations each part makes, how many bytes they add up to and the most
+    /// bytes live at once.
+    #[arg(long)]
+    alloc_stats: bool,
+
+    /// Worker threads for --parallel, defaulting to one per CPU.
+    #[arg(long, requires = "parallel")]
+    threads: Option<usize>,
+
+    /// File that per-part timings of each run are appended to.
+    #[arg(long, default_value = history::DEFAULT_FILE)]
+    history: PathBuf,
+
+    /// Don't record this run in the timing history.
+    #[arg(long)]
+    no_history: bool,
+
+    /// Show each part's time against the previous or best recorded one.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "previous")]
+    compare: Option<Baseline>,
+
+    /// Flag parts that got slower than their baseline by more than this many percent.
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 10.0,
+        requires = "compare"
+    )]
+    threshold: f64,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create `src/yYEAR/dayN.rs` from a template, register it and add empty input and example
+    /// files.
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle's title.
+        #[arg(long, default_value = "")]
+        title: String,
+    },
+    /// Run the worked examples of the selected days, or of every day.
+    Test { days: Option<Selection> },
+    /// List the registered days of the year, or of every year, with their parts, input file
+    /// and last verified status.
+    List {
+        /// Directory holding a `YEAR/dayN` input file per day.
+        #[arg(long, default_value = input::DEFAULT_DIR)]
+        input_dir: PathBuf,
+
+        /// Timing history that records the verdicts of earlier runs.
+        #[arg(long, default_value = history::DEFAULT_FILE)]
+        history: PathBuf,
+    },
+    /// Check a day's input against the assumptions its solution makes, reporting every problem
+    /// with its line and column.
+    CheckInput {
+        day: u8,
+
+        /// Directory holding a `YEAR/dayN` input file per day.
+        #[arg(long, default_value = input::DEFAULT_DIR)]
+        input_dir: PathBuf,
+
+        /// Check this file instead, or stdin when `-`.
+        #[arg(long, conflicts_with = "input_dir")]
+        input: Option<String>,
+    },
+}
+
+fn parse_timeout(arg: &str) -> Result<Duration, String> {
+    arg.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("{:?} is not a positive number of seconds", arg))
+}
+
+/// Parses the arguments, rejecting the ones for running days when a subcommand is given. Only
+/// `--year` applies to both.
+fn parse_args() -> Args {
+    let mut command = Args::command();
+    let matches = command.get_matches_mut();
+    if let Some((name, _)) = matches.subcommand() {
+        let misplaced = command
+            .get_arguments()
+            .filter(|arg| arg.get_id() != "year")
+            .find(|arg| {
+                matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+            })
+            .map(|arg| match arg.get_long() {
+                Some(long) => format!("--{}", long),
+                None => format!("<{}>", arg.get_id().as_str().to_uppercase()),
+            });
+        if let Some(arg) = misplaced {
+            let error = format!("{} can't be used with the {} subcommand", arg, name);
+            command.error(ErrorKind::ArgumentConflict, error).exit();
+        }
+    }
+    Args::from_arg_matches(&matches).unwrap_or_else(|err| err.exit())
+}
+
+/// Resolves the selected days, exiting with a usage error on unknown ones.
+fn resolve(year: u16, days: Option<&Selection>) -> Vec<&'static Solution> {
+    let solutions = match days {
+        Some(selection) => selection.resolve(year).map_err(|err| err.to_string()),
+        None => registry::year(year).map_err(|err| err.to_string()),
+    };
+    solutions.unwrap_or_else(|err| Args::command().error(ErrorKind::InvalidValue, err).exit())
+}
+
+fn new_day(year: u16, day: u8, title: &str) -> ExitCode {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    match scaffold::new_day(&src, year, day, title) {
+        Ok(changed) => {
+            for path in changed {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The verdict of the last recorded run of a solution, if it was ever recorded.
+fn last_verified(history: &History, solution: &Solution) -> &'static str {
+    let entries = history.last_run(&solution.id());
+    let verdicts = entries
+        .iter()
+        .map(|entry| entry.verdict.as_deref().unwrap_or("unknown"))
+        .collect::<Vec<_>>();
+    if verdicts.is_empty() {
+        "never"
+    } else if verdicts.contains(&"fail") {
+        "FAIL"
+    } else if verdicts.iter().all(|&verdict| verdict == "pass") {
+        "PASS"
+    } else {
+        "UNKNOWN"
+    }
+}
+
+fn list(input_dir: &Path, history: &Path, year: Option<u16>) -> ExitCode {
+    let history = History::load(history).unwrap_or_else(|err| {
+        eprintln!("warning: {}: {}", history.display(), err);
+        History::default()
+    });
+    let source = Source::Dir(input_dir.to_path_buf());
+    let mut solutions = SOLUTIONS
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.info.year == year))
+        .collect::<Vec<_>>();
+    solutions.sort_by_key(|solution| (solution.info.year, solution.info.day));
+    let width = solutions
+        .iter()
+        .map(|solution| solution.info.title.len())
+        .max()
+        .unwrap_or(0)
+        .max("Title".len());
+
+    println!(
+        "Year  {:>3}  {:<width$}  {:<5}  {:<5}  Verified",
+        "Day", "Title", "Parts", "Input"
+    );
+    for solution in solutions {
+        let info = &solution.info;
+        let parts = [Part::One, Part::Two]
+            .map(|part| {
+                if info.implements(part) {
+                    part.number().to_string()
+                } else {
+                    "-".to_string()
+                }
+            })
+            .join(" ");
+        let has_input = source
+            .path(info.year, info.day)
+            .is_some_and(|path| path.is_file());
+        let input = if has_input { "yes" } else { "no" };
+        println!(
+            "{}  {:>3}  {:<width$}  {:<5}  {:<5}  {}",
+            info.year,
+            info.day,
+            info.title,
+            parts,
+            input,
+            last_verified(&history, solution)
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+/// Exits with a usage error unless one of the selected parts has a variant called `name`. The
+/// parts without it run their default implementation.
+fn check_variant(name: &str, solutions: &[&Solution], parts: &[Part]) {
+    let mut available = Vec::new();
+    for solution in solutions {
+        for &part in parts {
+            for variant in solution.variants(part) {
+                if variant == name {
+                    return;
+                }
+                if !available.contains(&variant) {
+                    available.push(variant);
+                }
+            }
+        }
+    }
+    let error = format!(
+        "no selected part has a variant named {:?} (available: {})",
+        name,
+        available.join(", ")
+    );
+    Args::command().error(ErrorKind::InvalidValue, error).exit()
+}
+
+/// Validates a day's input, failing when it has problems or can't be read.
+fn check_input(solution: &Solution, source: &Source) -> ExitCode {
+    let info = &solution.info;
+    let input = match source.load(info.year, info.day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let name = source
+        .path(info.year, info.day)
+        .map_or("stdin".to_string(), |path| path.display().to_string());
+    match solution.validate(&input) {
+        None => println!("Day {} has no input validator", info.day),
+        Some(violations) if violations.is_empty() => println!("{}: OK", name),
+        Some(violations) => {
+            for violation in &violations {
+                println!("{}: {}", name, violation);
+            }
+            let plural = if violations.len() == 1 { "" } else { "s" };
+            println!("{} problem{} found", violations.len(), plural);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Runs the examples of every solution, failing when any of them did.
+fn test(solutions: &[&Solution]) -> ExitCode {
+    let (mut passed, mut total) = (0, 0);
+    for solution in solutions {
+        println!("Day {}:", solution.info.day);
+        if solution.examples.is_empty() {
+            println!("  no examples");
+        }
+        for report in solution.test() {
+            println!("  {}", report);
+            passed += usize::from(report.passed());
+            total += 1;
+        }
+        println!();
+    }
+    println!("{} of {} examples passed", passed, total);
+
+    if passed == total {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Loads the stored answers for a day's input, which are empty for stdin.
+///
+/// An example file has no stored answers. Its answers are those of the day's examples that have
+/// the same input.
+fn answers(job: &Job, content: Option<&str>) -> (Answers, String) {
+    let info = &job.solution.info;
+    let Some((path, input)) = job.source.answers(info.year, info.day) else {
+        return (Answers::default(), String::new());
+    };
+    if let (Source::Example(..), Some(content)) = (&job.source, content) {
+        return (
+            example::answers(job.solution.examples, &input, content),
+            input,
+        );
+    }
+    match Answers::load(&path) {
+        Ok(answers) => (answers, input),
+        Err(err) => {
+            eprintln!("warning: {}: {}", path.display(), err);
+            (Answers::default(), input)
+        }
+    }
+}
+
+/// A day to run on the inputs of one input set.
+struct Job {
+    solution: &'static Solution,
+    /// The named input set, or `None` for the default inputs.
+    set: Option<String>,
+    source: Source,
+}
+
+impl Job {
+    /// Whether the timings of this job can be compared with the recorded ones, which are all
+    /// timings of the default inputs.
+    fn comparable(&self) -> bool {
+        self.set.is_none() && matches!(self.source, Source::Dir(_))
+    }
+}
+
+/// The jobs for the selected days, which are a job per day unless every input set is run.
+fn jobs(args: &Args, year: u16, solutions: &[&'static Solution]) -> Vec<Job> {
+    if !args.all_sets {
+        let source = match (&args.input, &args.input_set) {
+            (Some(arg), _) => Source::from_arg(arg),
+            (None, Some(set)) => Source::set(&args.input_dir, set),
+            (None, None) => Source::Dir(args.input_dir.clone()),
+        };
+        let source = match (source, args.example) {
+            (Source::Dir(dir), Some(number)) => Source::Example(dir, number),
+            (source, _) => source,
+        };
+        return solutions
+            .iter()
+            .map(|&solution| Job {
+                solution,
+                set: args.input_set.clone(),
+                source: source.clone(),
+            })
+            .collect();
+    }
+
+    let sets = input::sets(&args.input_dir, year).unwrap_or_else(|err| {
+        eprintln!("warning: {}: {}", args.input_dir.display(), err);
+        Vec::new()
+    });
+    let mut jobs = Vec::new();
+    for &solution in solutions {
+        jobs.push(Job {
+            solution,
+            set: None,
+            source: Source::Dir(args.input_dir.clone()),
+        });
+        for set in &sets {
+            let source = Source::set(&args.input_dir, set);
+            // Sets only need the inputs of the days they were shared for.
+            if source
+                .path(year, solution.info.day)
+                .is_some_and(|path| path.is_file())
+            {
+                jobs.push(Job {
+                    solution,
+                    set: Some(set.clone()),
+                    source,
+                });
+            }
+        }
+    }
+    jobs
+}
+
+/// Loads a day's input, runs it and checks the answers stored with the input.
+///
+/// An example usually only has an answer for one of the parts, and then only that part runs.
+fn solve_day(job: &Job, options: &Options) -> DayReport {
+    let info = &job.solution.info;
+    let loaded = job.source.load(info.year, info.day);
+    let (answers, input) = answers(job, loaded.as_deref().ok());
+    let answered = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| answers.get(&input, part.number()).is_some())
+        .collect::<Vec<_>>();
+    let options = match (&job.source, options.part, &answered[..]) {
+        (Source::Example(..), None, &[part]) => Options {
+            part: Some(part),
+            ..options.clone()
+        },
+        _ => options.clone(),
+    };
+
+    let mut report = match loaded {
+        Ok(input) => job.solution.run(&input, &options),
+        Err(err) => DayReport::not_run(info.day, &options.parts(), err),
+    };
+    report.input_set = job.set.clone();
+    report.check(&answers, &input);
+    report
+}
+
+/// Runs every job and reports it, returning whether all parts succeeded.
+fn run_all(args: &Args, jobs: &[Job], options: &Options) -> io::Result<bool> {
+    let start = Instant::now();
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let previous = match args.compare {
+        Some(_) => History::load(&args.history).unwrap_or_else(|err| {
+            eprintln!("warning: {}: {}", args.history.display(), err);
+            History::default()
+        }),
+        None => History::default(),
+    };
+    let mut entries = Vec::new();
+
+    let mut reporter = Reporter::new(args.format, io::stdout().lock());
+    reporter.begin()?;
+
+    let mut success = true;
+    let verify = args.verify || args.all_sets;
+    let mut emit = |job: &Job, mut report: DayReport| {
+        let id = job.solution.id();
+        if let Some(baseline) = args.compare.filter(|_| job.comparable()) {
+            previous.compare(&id, &mut report, baseline, args.threshold);
+        }
+        if !args.no_history && job.comparable() {
+            entries.extend(history::entries(run, &id, &report));
+        }
+        let failed = report.failed() || (verify && report.mismatched());
+        success &= !failed;
+        reporter.day(&report)
+    };
+
+    if args.parallel {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(args.threads.unwrap_or(0))
+            .build()
+            .map_err(io::Error::other)?;
+        let reports: Vec<_> =
+            pool.install(|| jobs.par_iter().map(|job| solve_day(job, options)).collect());
+        for (job, report) in jobs.iter().zip(reports) {
+            emit(job, report)?;
+        }
+    } else {
+        for job in jobs {
+            emit(job, solve_day(job, options))?;
+        }
+    }
+    reporter.finish(start.elapsed())?;
+    if !entries.is_empty() {
+        if let Err(err) = history::append(&args.history, &entries) {
+            eprintln!(
+                "warning: could not record timings in {}: {}",
+                args.history.display(),
+                err
+            );
+        }
+    }
+
+    Ok(success)
+}
+
+fn main() -> ExitCode {
+    let args = parse_args();
+    let year = args.year.or_else(registry::latest_year).unwrap_or_default();
+    match &args.command {
+        Some(Command::New { day, title }) => return new_day(year, *day, title),
+        Some(Command::List { input_dir, history }) => return list(input_dir, history, args.year),
+        Some(Command::Test { days }) => return test(&resolve(year, days.as_ref())),
+        Some(Command::CheckInput {
+            day,
+            input_dir,
+            input,
+        }) => {
+            let Some(solution) = registry::find(year, *day) else {
+                let error = registry::UnknownDay { year, day: *day };
+                Args::command().error(ErrorKind::InvalidValue, error).exit()
+            };
+            let source = match input {
+                Some(arg) => Source::from_arg(arg),
+                None => Source::Dir(input_dir.clone()),
+            };
+            return check_input(solution, &source);
+        }
+        None => {}
+    }
+
+    let solutions = resolve(year, args.days.as_ref());
+    if args.alloc_stats {
+        allocation::enable();
+    }
+    let options = Options {
+        part: args.part.map(|part| match part {
+            1 => Part::One,
+            _ => Part::Two,
+        }),
+        bench: args.bench,
+        warmup: args.warmup,
+        timeout: args.timeout,
+        variants: match (&args.variant, args.all_variants) {
+            (Some(name), _) => Variants::Named(name.clone()),
+            (None, true) => Variants::All,
+            (None, false) => Variants::Default,
+        },
+        alloc_stats: args.alloc_stats,
+        // Machine-readable formats get no progress line, and neither does a redirected stderr or a
+        // parallel run, whose days would share the one line.
+        progress: (args.format == Format::Text && !args.parallel && io::stderr().is_terminal())
+            .then(|| Arc::new(Terminal::new()) as Arc<dyn progress::Sink>),
+    };
+    if args.input.is_some() && solutions.len() != 1 {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--input can only be used when running a single day",
+            )
+            .exit();
+    }
+    if let Some(name) = &args.variant {
+        check_variant(name, &solutions, &options.parts());
+    }
+
+    let jobs = jobs(&args, year, &solutions);
+    match run_all(&args, &jobs, &options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
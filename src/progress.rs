@@ -0,0 +1,172 @@
+//! Progress reporting for long-running parts.
+//!
+//! Solutions call [`set`] from their main loop. The runner attaches a [`Sink`] to the thread
+//! running a part, and without one the calls return right away.
+
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::Part;
+
+/// Receives the progress of running parts.
+pub trait Sink: Send + Sync {
+    fn update(&self, day: u8, part: Part, current: u64, total: u64);
+
+    /// Called when a part that may have reported progress is done.
+    fn finish(&self, day: u8, part: Part);
+}
+
+impl Debug for dyn Sink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Sink")
+    }
+}
+
+struct Attached {
+    sink: Arc<dyn Sink>,
+    day: u8,
+    part: Part,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Attached>> = const { RefCell::new(None) };
+}
+
+/// How many sinks are attached across all threads, so [`set`] can skip the thread-local lookup.
+static ATTACHED: AtomicUsize = AtomicUsize::new(0);
+
+/// Reports that the running part is `current` steps into `total`.
+#[inline]
+pub fn set(current: u64, total: u64) {
+    if ATTACHED.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    CURRENT.with(|attached| {
+        if let Some(attached) = &*attached.borrow() {
+            attached
+                .sink
+                .update(attached.day, attached.part, current, total);
+        }
+    });
+}
+
+/// Sends the progress of a part running on this thread to `sink` until the guard is dropped.
+pub fn attach(sink: Arc<dyn Sink>, day: u8, part: Part) -> Guard {
+    ATTACHED.fetch_add(1, Ordering::Relaxed);
+    CURRENT.with(|attached| *attached.borrow_mut() = Some(Attached { sink, day, part }));
+    Guard
+}
+
+/// Detaches the sink when dropped, letting it clean up after the part.
+pub struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(attached) = CURRENT.with(|attached| attached.borrow_mut().take()) {
+            attached.sink.finish(attached.day, attached.part);
+        }
+        ATTACHED.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Draws a single progress line on stderr, meant for a terminal and for one part at a time.
+///
+/// The line is redrawn at most every 100ms and cleared when the part finishes.
+pub struct Terminal {
+    last: Mutex<Option<Instant>>,
+}
+
+impl Terminal {
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new() -> Self {
+        Self {
+            last: Mutex::new(None),
+        }
+    }
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sink for Terminal {
+    fn update(&self, day: u8, part: Part, current: u64, total: u64) {
+        let mut last = self.last.lock().unwrap_or_else(|err| err.into_inner());
+        if last.is_some_and(|last| last.elapsed() < Self::INTERVAL) {
+            return;
+        }
+        *last = Some(Instant::now());
+
+        let line = line(day, part, current, total);
+        let _ = write!(io::stderr(), "\r\x1b[2K{}", line);
+    }
+
+    fn finish(&self, _day: u8, _part: Part) {
+        let mut last = self.last.lock().unwrap_or_else(|err| err.into_inner());
+        if last.take().is_some() {
+            let _ = write!(io::stderr(), "\r\x1b[2K");
+        }
+    }
+}
+
+fn line(day: u8, part: Part, current: u64, total: u64) -> String {
+    let percent = match total {
+        0 => 0.0,
+        total => current as f64 / total as f64 * 100.0,
+    };
+    format!(
+        "Day {} part {}: {}/{} ({:.1}%)",
+        day,
+        part.number(),
+        current,
+        total,
+        percent
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Record(Mutex<Vec<String>>);
+
+    impl Sink for Record {
+        fn update(&self, day: u8, part: Part, current: u64, total: u64) {
+            self.0.lock().unwrap().push(line(day, part, current, total));
+        }
+
+        fn finish(&self, day: u8, part: Part) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("Day {} part {}: done", day, part.number()));
+        }
+    }
+
+    #[test]
+    fn attach() {
+        let record = Arc::new(Record::default());
+        set(1, 2);
+        {
+            let _guard = super::attach(record.clone(), 5, Part::Two);
+            set(1, 4);
+        }
+        set(3, 4);
+        assert_eq!(
+            *record.0.lock().unwrap(),
+            ["Day 5 part 2: 1/4 (25.0%)", "Day 5 part 2: done"]
+        );
+    }
+}
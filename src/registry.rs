@@ -0,0 +1,285 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    example::{Example, ExampleReport},
+    input::{self, Normalize},
+    report::DayReport,
+    run,
+    validate::{Validator, Violation},
+    Info, Options, Part, Puzzle, PuzzleError, DEFAULT_VARIANT,
+};
+
+/// A registered puzzle, with its generic runner erased so days can live in one table.
+pub struct Solution {
+    pub info: Info,
+    runner: fn(&str, &Options) -> DayReport,
+    pub examples: &'static [Example],
+    solver: fn(&Example) -> Result<String, PuzzleError>,
+    normalize: Normalize,
+    validator: Option<Validator>,
+    variants: fn(Part) -> Vec<&'static str>,
+}
+
+/// The names of the implementations of a part, the default one first.
+fn variant_names<T: Puzzle>(part: Part) -> Vec<&'static str> {
+    let others = match part {
+        Part::One => T::PART1_VARIANTS
+            .iter()
+            .map(|variant| variant.name)
+            .collect(),
+        Part::Two => T::PART2_VARIANTS
+            .iter()
+            .map(|variant| variant.name)
+            .collect(),
+    };
+    [vec![DEFAULT_VARIANT], others].concat()
+}
+
+impl Solution {
+    const fn new<T: Puzzle>() -> Self {
+        Self {
+            info: T::INFO,
+            runner: run::<T>,
+            examples: T::EXAMPLES,
+            solver: Example::solve::<T>,
+            normalize: T::NORMALIZE,
+            validator: T::VALIDATOR,
+            variants: variant_names::<T>,
+        }
+    }
+
+    /// A name for the solution that stays the same across commits, used to key timing history.
+    /// Histories from before it had a year key 2023's days without one, see [`History`].
+    ///
+    /// [`History`]: crate::history::History
+    pub fn id(&self) -> String {
+        format!("{}/day{}", self.info.year, self.info.day)
+    }
+
+    /// The names of the implementations of a part, the default one first.
+    pub fn variants(&self, part: Part) -> Vec<&'static str> {
+        (self.variants)(part)
+    }
+
+    pub fn run(&self, input: &str, options: &Options) -> DayReport {
+        (self.runner)(input, options)
+    }
+
+    /// Runs every example of the puzzle.
+    pub fn test(&self) -> Vec<ExampleReport> {
+        self.examples
+            .iter()
+            .map(|&example| ExampleReport {
+                example,
+                result: (self.solver)(&example),
+            })
+            .collect()
+    }
+
+    /// Checks an input with the puzzle's validator, or returns `None` when it doesn't have one.
+    pub fn validate(&self, input: &str) -> Option<Vec<Violation>> {
+        let validator = self.validator?;
+        Some(validator(&input::normalize(input, self.normalize)))
+    }
+}
+
+/// Every solved day of every year, one per line so `aoc23 new` can append to it.
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::new::<crate::y2023::day1::Day1>(),
+    Solution::new::<crate::y2023::day2::Day2>(),
+    Solution::new::<crate::y2023::day3::Day3>(),
+    Solution::new::<crate::y2023::day4::Day4>(),
+    Solution::new::<crate::y2023::day5::Day5>(),
+    Solution::new::<crate::y2023::day6::Day6>(),
+    Solution::new::<crate::y2023::day7::Day7>(),
+    Solution::new::<crate::y2023::day8::Day8>(),
+    Solution::new::<crate::y2023::day9::Day9>(),
+    Solution::new::<crate::y2023::day10::Day10>(),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.info.year == year && solution.info.day == day)
+}
+
+/// The solutions of one year, in day order.
+pub fn in_year(year: u16) -> Vec<&'static Solution> {
+    let mut solutions = SOLUTIONS
+        .iter()
+        .filter(|solution| solution.info.year == year)
+        .collect::<Vec<_>>();
+    solutions.sort_by_key(|solution| solution.info.day);
+    solutions
+}
+
+/// Every solution of one year, failing when the year has none.
+pub fn year(year: u16) -> Result<Vec<&'static Solution>, UnknownYear> {
+    let solutions = in_year(year);
+    if solutions.is_empty() {
+        return Err(UnknownYear(year));
+    }
+    Ok(solutions)
+}
+
+/// The most recent year with a registered solution, which is the one run by default.
+pub fn latest_year() -> Option<u16> {
+    SOLUTIONS.iter().map(|solution| solution.info.year).max()
+}
+
+/// A set of days given on the command line, e.g. `7`, `3..8` or `1,5,9`.
+///
+/// Ranges are inclusive on both ends and can be mixed with single days: `1,3..5`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection(Vec<u8>);
+
+impl Selection {
+    pub fn days(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Resolves every selected day of `year`, failing on the first one that isn't registered.
+    pub fn resolve(&self, year: u16) -> Result<Vec<&'static Solution>, UnknownDay> {
+        self.0
+            .iter()
+            .map(|&day| find(year, day).ok_or(UnknownDay { year, day }))
+            .collect()
+    }
+}
+
+fn parse_day(input: &str) -> Result<u8, String> {
+    input
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| format!("{:?} is not a valid day", input.trim()))
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+        for item in input.split(',') {
+            if let Some((start, end)) = item.split_once("..") {
+                let start = parse_day(start)?;
+                let end = parse_day(end.strip_prefix('=').unwrap_or(end))?;
+                if start > end {
+                    return Err(format!("{}..{} is an empty range", start, end));
+                }
+                days.extend(start..=end);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+        days.sort();
+        days.dedup();
+        Ok(Self(days))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownDay {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Display for UnknownDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let registered = in_year(self.year)
+            .iter()
+            .map(|solution| solution.info.day.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "day {} of {} has no registered solution (available: {})",
+            self.day,
+            self.year,
+            if registered.is_empty() {
+                "none"
+            } else {
+                &registered
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownYear(pub u16);
+
+impl Display for UnknownYear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut years = SOLUTIONS
+            .iter()
+            .map(|solution| solution.info.year)
+            .collect::<Vec<_>>();
+        years.sort();
+        years.dedup();
+        let years = years.iter().map(u16::to_string).collect::<Vec<_>>();
+        write!(
+            f,
+            "{} has no registered solutions (available: {})",
+            self.0,
+            if years.is_empty() {
+                "none".to_string()
+            } else {
+                years.join(", ")
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single() {
+        assert_eq!("7".parse::<Selection>().unwrap().days(), &[7]);
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(
+            "3..8".parse::<Selection>().unwrap().days(),
+            &[3, 4, 5, 6, 7, 8]
+        );
+        assert_eq!("3..=5".parse::<Selection>().unwrap().days(), &[3, 4, 5]);
+        assert!("8..3".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!("9,1,5".parse::<Selection>().unwrap().days(), &[1, 5, 9]);
+        assert_eq!("1,3..4,4".parse::<Selection>().unwrap().days(), &[1, 3, 4]);
+        assert!("1,x".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn variants() {
+        let day5 = find(2023, 5).unwrap();
+        assert_eq!(day5.variants(Part::One), ["default"]);
+        assert_eq!(day5.variants(Part::Two), ["default", "ranges"]);
+    }
+
+    #[test]
+    fn unknown() {
+        let selection = "1,26".parse::<Selection>().unwrap();
+        assert_eq!(
+            selection.resolve(2023).err(),
+            Some(UnknownDay {
+                year: 2023,
+                day: 26
+            })
+        );
+        let error = selection.resolve(1999).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "day 1 of 1999 has no registered solution (available: none)"
+        );
+        assert_eq!(
+            year(1999).err().unwrap().to_string(),
+            "1999 has no registered solutions (available: 2023)"
+        );
+    }
+}
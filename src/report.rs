@@ -0,0 +1,665 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+
+use crate::{
+    allocation::AllocStats,
+    answers::{Answers, Verdict},
+    span::Node,
+    timing::{Elapsed, Stats},
+    Part, DEFAULT_VARIANT,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    Failed(String),
+    /// The part didn't finish within the timeout.
+    TimedOut,
+    /// The part isn't listed in the puzzle's [`Info::parts`](crate::Info::parts), so it didn't run.
+    NotImplemented,
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "ok",
+            Outcome::Failed(_) => "failed",
+            Outcome::TimedOut => "timeout",
+            Outcome::NotImplemented => "not_implemented",
+        }
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Outcome::Solved(answer) => Some(answer),
+            Outcome::Failed(_) | Outcome::TimedOut | Outcome::NotImplemented => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Outcome::Solved(_) | Outcome::TimedOut | Outcome::NotImplemented => None,
+            Outcome::Failed(error) => Some(error),
+        }
+    }
+}
+
+/// How a part's time compares to an earlier one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    /// The change relative to the baseline, in percent.
+    pub change: f64,
+    /// Whether the part slowed down by more than the allowed threshold.
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// The change from `baseline` to `time`, in percent.
+    pub fn change(time: Duration, baseline: Duration) -> f64 {
+        if baseline.is_zero() {
+            return 0.0;
+        }
+        (time.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.1}% vs {}", self.change, Elapsed(self.baseline))?;
+        if self.regressed {
+            write!(f, ", SLOWER")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    /// The implementation that produced this report, [`DEFAULT_VARIANT`] for `part1`/`part2`.
+    pub variant: String,
+    pub outcome: Outcome,
+    /// The time of a single run, or the median when benchmarking.
+    pub time: Duration,
+    pub stats: Option<Stats>,
+    /// What a single run allocated, when allocations were counted.
+    pub allocs: Option<AllocStats>,
+    /// The spans of a single run, which are only collected with the `spans` feature.
+    pub spans: Vec<Node>,
+    /// How the answer compares to the stored one, if it was checked.
+    pub verdict: Option<Verdict>,
+    /// How the time compares to the timing history, if it was compared.
+    pub comparison: Option<Comparison>,
+}
+
+impl PartReport {
+    pub fn solved(part: Part, answer: impl ToString, time: Duration) -> Self {
+        Self {
+            part,
+            variant: DEFAULT_VARIANT.to_string(),
+            outcome: Outcome::Solved(answer.to_string()),
+            time,
+            stats: None,
+            allocs: None,
+            spans: Vec::new(),
+            verdict: None,
+            comparison: None,
+        }
+    }
+
+    /// A part that was abandoned after running for `timeout`.
+    pub fn timed_out(part: Part, timeout: Duration) -> Self {
+        Self {
+            part,
+            variant: DEFAULT_VARIANT.to_string(),
+            outcome: Outcome::TimedOut,
+            time: timeout,
+            stats: None,
+            allocs: None,
+            spans: Vec::new(),
+            verdict: None,
+            comparison: None,
+        }
+    }
+
+    /// A part that was skipped because the puzzle doesn't implement it yet.
+    pub fn not_implemented(part: Part) -> Self {
+        Self {
+            part,
+            variant: DEFAULT_VARIANT.to_string(),
+            outcome: Outcome::NotImplemented,
+            time: Duration::ZERO,
+            stats: None,
+            allocs: None,
+            spans: Vec::new(),
+            verdict: None,
+            comparison: None,
+        }
+    }
+
+    pub fn failed(part: Part, error: impl ToString) -> Self {
+        Self {
+            part,
+            variant: DEFAULT_VARIANT.to_string(),
+            outcome: Outcome::Failed(error.to_string()),
+            time: Duration::ZERO,
+            stats: None,
+            allocs: None,
+            spans: Vec::new(),
+            verdict: None,
+            comparison: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    /// The named input set the day ran on, or `None` for the default inputs.
+    pub input_set: Option<String>,
+    /// The time spent parsing, or the median when benchmarking.
+    pub parse_time: Duration,
+    pub parse_stats: Option<Stats>,
+    pub parse_spans: Vec<Node>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    /// A report for a day that couldn't be run at all, failing every part with the same error.
+    pub fn not_run(day: u8, parts: &[Part], error: impl ToString) -> Self {
+        let error = error.to_string();
+        Self {
+            day,
+            input_set: None,
+            parse_time: Duration::ZERO,
+            parse_stats: None,
+            parse_spans: Vec::new(),
+            parts: parts
+                .iter()
+                .map(|&part| PartReport::failed(part, &error))
+                .collect(),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    /// Whether any part failed or timed out. Parts that aren't implemented yet don't count.
+    pub fn failed(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| !matches!(part.outcome, Outcome::Solved(_) | Outcome::NotImplemented))
+    }
+
+    /// The reports of every variant that ran for a part.
+    pub fn variants(&self, part: Part) -> Vec<&PartReport> {
+        self.parts
+            .iter()
+            .filter(|report| report.part == part)
+            .collect()
+    }
+
+    /// Whether any answer differs from its stored answer.
+    pub fn mismatched(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part.verdict, Some(Verdict::Fail(_))))
+    }
+
+    /// Checks every solved part against the answers stored for `input`.
+    ///
+    /// Parts that didn't solve fail the check when an answer is stored, so it is still reported.
+    /// Parts that aren't implemented yet aren't checked.
+    pub fn check(&mut self, answers: &Answers, input: &str) {
+        for part in &mut self.parts {
+            let number = part.part.number();
+            part.verdict = match &part.outcome {
+                Outcome::Solved(answer) => Some(answers.check(input, number, answer)),
+                Outcome::Failed(_) | Outcome::TimedOut => answers
+                    .get(input, number)
+                    .map(|expected| Verdict::Fail(expected.to_string())),
+                Outcome::NotImplemented => None,
+            };
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/// Writes day reports as they come in, in the chosen format.
+pub struct Reporter<W: Write> {
+    format: Format,
+    out: W,
+    records: usize,
+    /// Time spent parsing and solving across all reported days, without parts that timed out.
+    solving: Duration,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(format: Format, out: W) -> Self {
+        Self {
+            format,
+            out,
+            records: 0,
+            solving: Duration::ZERO,
+        }
+    }
+
+    pub fn begin(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Text => Ok(()),
+            Format::Json => writeln!(self.out, "["),
+            Format::Csv => writeln!(
+                self.out,
+                "day,input_set,part,variant,answer,duration_ns,parse_ns,allocs,alloc_bytes,peak_bytes,status,verdict,expected,baseline_ns,regressed,error"
+            ),
+        }
+    }
+
+    pub fn day(&mut self, report: &DayReport) -> io::Result<()> {
+        self.solving += report.parse_time;
+        self.solving += report
+            .parts
+            .iter()
+            .filter(|part| part.outcome != Outcome::TimedOut)
+            .map(|part| part.time)
+            .sum::<Duration>();
+        match self.format {
+            Format::Text => self.text(report),
+            Format::Json => report.parts.iter().try_for_each(|part| {
+                let separator = if self.records == 0 { "" } else { ",\n" };
+                self.records += 1;
+                write!(self.out, "{}  {}", separator, json_record(report, part))
+            }),
+            Format::Csv => report
+                .parts
+                .iter()
+                .try_for_each(|part| writeln!(self.out, "{}", csv_record(report, part))),
+        }
+    }
+
+    /// Ends the output, given the wall-clock time of the whole run.
+    ///
+    /// The text summary also shows the time summed over every parse and part that finished, which
+    /// exceeds the wall-clock time when days ran in parallel. Parts that timed out are left out, as
+    /// their time is only the timeout.
+    pub fn finish(&mut self, wall: Duration) -> io::Result<()> {
+        match self.format {
+            Format::Text => writeln!(
+                self.out,
+                "Wall time: {}, summed solve time: {}",
+                Elapsed(wall),
+                Elapsed(self.solving)
+            ),
+            Format::Csv => Ok(()),
+            Format::Json if self.records == 0 => writeln!(self.out, "]"),
+            Format::Json => writeln!(self.out, "\n]"),
+        }
+    }
+
+    fn text(&mut self, report: &DayReport) -> io::Result<()> {
+        match &report.input_set {
+            Some(set) => writeln!(self.out, "Day {} ({}):", report.day, set)?,
+            None => writeln!(self.out, "Day {}:", report.day)?,
+        }
+        match &report.parse_stats {
+            Some(stats) => writeln!(self.out, "  Parse: {}", stats)?,
+            None => writeln!(self.out, "  Parse: {}", Elapsed(report.parse_time))?,
+        }
+        self.spans(&report.parse_spans, 2)?;
+        for part in &report.parts {
+            let label = match report.variants(part.part).len() {
+                1 if part.variant == DEFAULT_VARIANT => format!("Part {}", part.part.number()),
+                _ => format!("Part {} [{}]", part.part.number(), part.variant),
+            };
+            let answer = match (&part.outcome, &part.verdict) {
+                (Outcome::Solved(answer), Some(verdict)) => format!("{} {}", answer, verdict),
+                (Outcome::Solved(answer), None) => answer.clone(),
+                (Outcome::Failed(error), verdict) => {
+                    writeln!(
+                        self.out,
+                        "  {}: FAILED - {}{}",
+                        label,
+                        error,
+                        expected(verdict)
+                    )?;
+                    continue;
+                }
+                (Outcome::NotImplemented, _) => {
+                    writeln!(self.out, "  {}: not implemented", label)?;
+                    continue;
+                }
+                (Outcome::TimedOut, verdict) => {
+                    writeln!(
+                        self.out,
+                        "  {}: TIMEOUT after {}{}",
+                        label,
+                        Elapsed(part.time),
+                        expected(verdict)
+                    )?;
+                    continue;
+                }
+            };
+            let time = match &part.stats {
+                Some(stats) => stats.to_string(),
+                None => Elapsed(part.time).to_string(),
+            };
+            match &part.comparison {
+                Some(comparison) => writeln!(
+                    self.out,
+                    "  {}: {} - {} ({})",
+                    label, answer, time, comparison
+                )?,
+                None => writeln!(self.out, "  {}: {} - {}", label, answer, time)?,
+            }
+            if let Some(allocs) = &part.allocs {
+                writeln!(self.out, "    {}", allocs)?;
+            }
+            self.spans(&part.spans, 2)?;
+        }
+        for part in [Part::One, Part::Two] {
+            self.variant_table(report, part)?;
+        }
+        writeln!(self.out, "  Total: {}", Elapsed(report.total()))?;
+        writeln!(self.out)
+    }
+
+    /// Prints a tree of spans, indenting each level by two more spaces.
+    fn spans(&mut self, nodes: &[Node], depth: usize) -> io::Result<()> {
+        for node in nodes {
+            let calls = if node.calls == 1 { "call" } else { "calls" };
+            writeln!(
+                self.out,
+                "{:indent$}{}: {} ({} {})",
+                "",
+                node.name,
+                Elapsed(node.time),
+                node.calls,
+                calls,
+                indent = depth * 2
+            )?;
+            self.spans(&node.children, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Compares the times of the variants of a part that solved it, fastest first.
+    fn variant_table(&mut self, report: &DayReport, part: Part) -> io::Result<()> {
+        let mut solved = report
+            .variants(part)
+            .into_iter()
+            .filter(|report| matches!(report.outcome, Outcome::Solved(_)))
+            .collect::<Vec<_>>();
+        if solved.len() < 2 {
+            return Ok(());
+        }
+        solved.sort_by_key(|report| report.time);
+
+        let fastest = solved[0].time.as_secs_f64();
+        let width = solved.iter().map(|report| report.variant.len()).max();
+        let width = width.unwrap_or(0);
+        writeln!(self.out, "  Part {} variants:", part.number())?;
+        for report in solved {
+            let time = Elapsed(report.time).to_string();
+            let relative = if fastest > 0.0 {
+                report.time.as_secs_f64() / fastest
+            } else {
+                1.0
+            };
+            writeln!(
+                self.out,
+                "    {:<width$}  {:>9}  {:.2}x",
+                report.variant, time, relative
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The stored answer of a part that didn't solve, as a suffix for its line.
+fn expected(verdict: &Option<Verdict>) -> String {
+    match verdict.as_ref().and_then(Verdict::expected) {
+        Some(expected) => format!(" (expected {})", expected),
+        None => String::new(),
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_string(), json_string)
+}
+
+fn json_number(value: Option<u64>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+fn json_record(day: &DayReport, part: &PartReport) -> String {
+    format!(
+        r#"{{"day": {}, "input_set": {}, "part": {}, "variant": {}, "answer": {}, "duration_ns": {}, "parse_ns": {}, "allocs": {}, "alloc_bytes": {}, "peak_bytes": {}, "status": {}, "verdict": {}, "expected": {}, "baseline_ns": {}, "regressed": {}, "error": {}}}"#,
+        day.day,
+        json_option(day.input_set.as_deref()),
+        part.part.number(),
+        json_string(&part.variant),
+        json_option(part.outcome.answer()),
+        part.time.as_nanos(),
+        day.parse_time.as_nanos(),
+        json_number(part.allocs.map(|allocs| allocs.count)),
+        json_number(part.allocs.map(|allocs| allocs.bytes)),
+        json_number(part.allocs.map(|allocs| allocs.peak)),
+        json_string(part.outcome.status()),
+        json_option(part.verdict.as_ref().map(Verdict::name)),
+        json_option(part.verdict.as_ref().and_then(Verdict::expected)),
+        part.comparison
+            .as_ref()
+            .map_or("null".to_string(), |c| c.baseline.as_nanos().to_string()),
+        part.comparison
+            .as_ref()
+            .map_or("null".to_string(), |c| c.regressed.to_string()),
+        json_option(part.outcome.error()),
+    )
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_number(value: Option<u64>) -> String {
+    value.map_or(String::new(), |value| value.to_string())
+}
+
+fn csv_record(day: &DayReport, part: &PartReport) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        day.day,
+        csv_field(day.input_set.as_deref().unwrap_or_default()),
+        part.part.number(),
+        csv_field(&part.variant),
+        csv_field(part.outcome.answer().unwrap_or_default()),
+        part.time.as_nanos(),
+        day.parse_time.as_nanos(),
+        csv_number(part.allocs.map(|allocs| allocs.count)),
+        csv_number(part.allocs.map(|allocs| allocs.bytes)),
+        csv_number(part.allocs.map(|allocs| allocs.peak)),
+        part.outcome.status(),
+        part.verdict.as_ref().map_or("", Verdict::name),
+        csv_field(
+            part.verdict
+                .as_ref()
+                .and_then(Verdict::expected)
+                .unwrap_or_default()
+        ),
+        part.comparison
+            .as_ref()
+            .map_or(String::new(), |c| c.baseline.as_nanos().to_string()),
+        part.comparison
+            .as_ref()
+            .map_or(String::new(), |c| c.regressed.to_string()),
+        csv_field(part.outcome.error().unwrap_or_default()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn report() -> DayReport {
+        DayReport {
+            day: 7,
+            input_set: None,
+            parse_time: Duration::from_micros(3),
+            parse_stats: None,
+            parse_spans: vec![Node {
+                name: "lines",
+                time: Duration::from_micros(2),
+                calls: 1,
+                children: vec![Node {
+                    name: "hand",
+                    time: Duration::from_micros(1),
+                    calls: 5,
+                    children: Vec::new(),
+                }],
+            }],
+            parts: vec![
+                PartReport {
+                    allocs: Some(AllocStats {
+                        count: 4,
+                        bytes: 2048,
+                        peak: 1024,
+                    }),
+                    verdict: Some(Verdict::Fail("6441".to_string())),
+                    comparison: Some(Comparison {
+                        baseline: Duration::from_micros(10),
+                        change: 20.0,
+                        regressed: true,
+                    }),
+                    ..PartReport::solved(Part::One, 6440, Duration::from_micros(12))
+                },
+                PartReport::failed(Part::Two, "invalid input: invalid hand: \"x, y\""),
+            ],
+        }
+    }
+
+    fn render(format: Format) -> String {
+        let mut reporter = Reporter::new(format, Vec::new());
+        reporter.begin().unwrap();
+        reporter.day(&report()).unwrap();
+        reporter.finish(Duration::from_micros(20)).unwrap();
+        String::from_utf8(reporter.out).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json),
+            r#"[
+  {"day": 7, "input_set": null, "part": 1, "variant": "default", "answer": "6440", "duration_ns": 12000, "parse_ns": 3000, "allocs": 4, "alloc_bytes": 2048, "peak_bytes": 1024, "status": "ok", "verdict": "fail", "expected": "6441", "baseline_ns": 10000, "regressed": true, "error": null},
+  {"day": 7, "input_set": null, "part": 2, "variant": "default", "answer": null, "duration_ns": 0, "parse_ns": 3000, "allocs": null, "alloc_bytes": null, "peak_bytes": null, "status": "failed", "verdict": null, "expected": null, "baseline_ns": null, "regressed": null, "error": "invalid input: invalid hand: \"x, y\""}
+]
+"#
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv),
+            r#"day,input_set,part,variant,answer,duration_ns,parse_ns,allocs,alloc_bytes,peak_bytes,status,verdict,expected,baseline_ns,regressed,error
+7,,1,default,6440,12000,3000,4,2048,1024,ok,fail,6441,10000,true,
+7,,2,default,,0,3000,,,,failed,,,,,"invalid input: invalid hand: ""x, y"""
+"#
+        );
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(Format::Text),
+            r#"Day 7:
+  Parse: 3.00µs
+    lines: 2.00µs (1 call)
+      hand: 1.00µs (5 calls)
+  Part 1: 6440 FAIL (expected 6441) - 12.00µs (+20.0% vs 10.00µs, SLOWER)
+    4 allocations, 2.0 KiB total, 1.0 KiB peak
+  Part 2: FAILED - invalid input: invalid hand: "x, y"
+  Total: 15.00µs
+
+Wall time: 20.00µs, summed solve time: 15.00µs
+"#
+        );
+    }
+
+    #[test]
+    fn summed_time() {
+        let mut report = report();
+        report.parts[1] = PartReport::timed_out(Part::Two, Duration::from_secs(30));
+
+        let mut reporter = Reporter::new(Format::Text, Vec::new());
+        reporter.day(&report).unwrap();
+        reporter.finish(Duration::from_secs(30)).unwrap();
+        let text = String::from_utf8(reporter.out).unwrap();
+        assert!(text.ends_with("Wall time: 30.00s, summed solve time: 15.00µs\n"));
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("day7.ex1 1 6440\nday7.ex1 2 5905\n").unwrap();
+        let mut report = report();
+        report.check(&answers, "day7.ex1");
+        assert_eq!(report.parts[0].verdict, Some(Verdict::Pass));
+        assert_eq!(
+            report.parts[1].verdict,
+            Some(Verdict::Fail("5905".to_string()))
+        );
+
+        let mut reporter = Reporter::new(Format::Text, Vec::new());
+        reporter.day(&report).unwrap();
+        let text = String::from_utf8(reporter.out).unwrap();
+        assert!(text.contains(
+            "  Part 2: FAILED - invalid input: invalid hand: \"x, y\" (expected 5905)\n"
+        ));
+    }
+
+    #[test]
+    fn empty_json() {
+        let mut reporter = Reporter::new(Format::Json, Vec::new());
+        reporter.begin().unwrap();
+        reporter.finish(Duration::ZERO).unwrap();
+        assert_eq!(String::from_utf8(reporter.out).unwrap(), "[\n]\n");
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = r##"use crate::{example::Example, Info, Puzzle, PuzzleError};
+
+pub struct Day{N};
+
+impl Puzzle for Day{N} {
+    const INFO: Info = Info {
+        year: {YEAR},
+        day: {N},
+        title: {TITLE},
+        // List `crate::Part::One` and `Two` here once they are solved.
+        parts: &[],
+    };
+
+    type Parsed = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_lines: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        Err(PuzzleError::NotImplemented)
+    }
+
+    fn part2(_lines: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        Err(PuzzleError::NotImplemented)
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/{YEAR}/day{N}.ex1");
+
+// Paste the example into `src/input/{YEAR}/day{N}.ex1`, fill in its answers here, then drop the
+// `#[ignore]`s.
+crate::examples! {
+    Day{N} {
+        #[ignore]
+        example1: One(EXAMPLE) => 0,
+        #[ignore]
+        example2: Two(EXAMPLE) => 0,
+    }
+}
+"##;
+
+/// The source of a new, unsolved day.
+pub fn render(year: u16, day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace("{YEAR}", &year.to_string())
+        .replace("{N}", &day.to_string())
+        .replace("{TITLE}", &format!("{:?}", title))
+}
+
+/// Adds `pub mod <module>;` to the module declarations in `source`, keeping them sorted by name.
+pub fn register_module(source: &str, module: &str) -> Option<String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let name = |line: &str| {
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        line.strip_prefix("mod ")?
+            .strip_suffix(';')
+            .map(str::to_string)
+    };
+
+    let start = lines.iter().position(|line| name(line).is_some())?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|line| name(line).is_some())
+            .count();
+    if lines[start..end]
+        .iter()
+        .any(|line| name(line).as_deref() == Some(module))
+    {
+        return None;
+    }
+
+    let declaration = format!("pub mod {};", module);
+    let mut modules = lines[start..end].to_vec();
+    modules.push(&declaration);
+    modules.sort_by_key(|line| name(line));
+
+    let mut result = lines[..start].to_vec();
+    result.extend(modules);
+    result.extend(&lines[end..]);
+    Some(result.join("\n") + "\n")
+}
+
+/// Appends the new day to the end of the `SOLUTIONS` table in `registry.rs`.
+pub fn register_solution(registry: &str, year: u16, day: u8) -> Option<String> {
+    let entry = format!(
+        "    Solution::new::<crate::y{0}::day{1}::Day{1}>(),",
+        year, day
+    );
+    if registry.contains(&entry) {
+        return None;
+    }
+
+    let table = registry.find("pub const SOLUTIONS")?;
+    let end = table + registry[table..].find("\n];")?;
+    Some(format!(
+        "{}\n{}{}",
+        &registry[..end],
+        entry,
+        &registry[end..]
+    ))
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day already has a source file.
+    Exists(PathBuf),
+    /// A module list or `registry.rs` doesn't have the expected shape.
+    Register(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Register(path) => {
+                write!(f, "could not register the day in {}", path.display())
+            }
+            ScaffoldError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+fn create_dir(path: &Path) -> Result<(), ScaffoldError> {
+    std::fs::create_dir_all(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    std::fs::write(path, content).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+/// Creates `src/yYEAR/dayN.rs` and empty input and example files under `src`, and registers the
+/// day.
+///
+/// The year's module is created along with its first day. Returns the files that were created
+/// or changed.
+pub fn new_day(src: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let year_dir = src.join(format!("y{}", year));
+    let source = year_dir.join(format!("day{}.rs", day));
+    if source.exists() {
+        return Err(ScaffoldError::Exists(source));
+    }
+
+    let mut modules = Vec::new();
+    let year_mod = year_dir.join("mod.rs");
+    if year_mod.exists() {
+        let content = register_module(&read(&year_mod)?, &format!("day{}", day))
+            .ok_or_else(|| ScaffoldError::Register(year_mod.clone()))?;
+        modules.push((year_mod, content));
+    } else {
+        let lib = src.join("lib.rs");
+        let content = register_module(&read(&lib)?, &format!("y{}", year))
+            .ok_or_else(|| ScaffoldError::Register(lib.clone()))?;
+        modules.push((lib, content));
+        let content = format!(
+            "//! Solutions for Advent of Code {}.\n\npub mod day{};\n",
+            year, day
+        );
+        modules.push((year_mod, content));
+    }
+    let registry = src.join("registry.rs");
+    let registry_content = register_solution(&read(&registry)?, year, day)
+        .ok_or_else(|| ScaffoldError::Register(registry.clone()))?;
+    modules.push((registry, registry_content));
+
+    create_dir(&year_dir)?;
+    write(&source, &render(year, day, title))?;
+    let mut changed = vec![source];
+    for (path, content) in modules {
+        write(&path, &content)?;
+        changed.push(path);
+    }
+
+    let input_dir = src.join("input").join(year.to_string());
+    create_dir(&input_dir)?;
+    for name in [format!("day{}", day), format!("day{}.ex1", day)] {
+        let input = input_dir.join(name);
+        if !input.exists() {
+            write(&input, "")?;
+            changed.push(input);
+        }
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = r#"pub mod answers;
+mod error;
+pub mod slurp;
+pub mod y2023;
+
+pub use error::PuzzleError;
+"#;
+
+    const REGISTRY: &str = r#"pub const SOLUTIONS: &[Solution] = &[
+    Solution::new::<crate::y2023::day1::Day1>(),
+];
+
+pub fn find() {}
+"#;
+
+    #[test]
+    fn module() {
+        assert_eq!(
+            register_module(LIB, "y2022").unwrap(),
+            r#"pub mod answers;
+mod error;
+pub mod slurp;
+pub mod y2022;
+pub mod y2023;
+
+pub use error::PuzzleError;
+"#
+        );
+        assert_eq!(register_module(LIB, "y2023"), None);
+        assert_eq!(
+            register_module("//! 2023\n\npub mod day1;\npub mod day2;\n", "day10").unwrap(),
+            "//! 2023\n\npub mod day1;\npub mod day10;\npub mod day2;\n"
+        );
+    }
+
+    #[test]
+    fn solution() {
+        let registry = register_solution(REGISTRY, 2022, 11).unwrap();
+        assert_eq!(
+            registry,
+            r#"pub const SOLUTIONS: &[Solution] = &[
+    Solution::new::<crate::y2023::day1::Day1>(),
+    Solution::new::<crate::y2022::day11::Day11>(),
+];
+
+pub fn find() {}
+"#
+        );
+        assert_eq!(register_solution(&registry, 2022, 11), None);
+    }
+
+    #[test]
+    fn template() {
+        let source = render(2023, 11, "Cosmic \"Expansion\"");
+        assert!(source.contains("pub struct Day11;"));
+        assert!(source.contains(r#"title: "Cosmic \"Expansion\"","#));
+        assert!(source.contains("    Day11 {\n        #[ignore]"));
+        assert!(source.contains(r#"include_str!("../input/2023/day11.ex1")"#));
+    }
+
+    #[test]
+    fn new_year() {
+        let src = std::env::temp_dir().join(format!("aoc23-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("lib.rs"), LIB).unwrap();
+        std::fs::write(src.join("registry.rs"), REGISTRY).unwrap();
+
+        new_day(&src, 2022, 1, "").unwrap();
+        new_day(&src, 2022, 2, "").unwrap();
+        assert!(matches!(
+            new_day(&src, 2022, 2, ""),
+            Err(ScaffoldError::Exists(_))
+        ));
+        let year_mod = std::fs::read_to_string(src.join("y2022/mod.rs")).unwrap();
+        let registry = std::fs::read_to_string(src.join("registry.rs")).unwrap();
+        let lib = std::fs::read_to_string(src.join("lib.rs")).unwrap();
+        let input = src.join("input/2022/day2").is_file();
+        let example = src.join("input/2022/day2.ex1").is_file();
+        std::fs::remove_dir_all(&src).unwrap();
+
+        assert_eq!(
+            year_mod,
+            "//! Solutions for Advent of Code 2022.\n\npub mod day1;\npub mod day2;\n"
+        );
+        assert!(registry.contains("crate::y2022::day2::Day2"));
+        assert!(lib.contains("pub mod y2022;\npub mod y2023;"));
+        assert!(input);
+        assert!(example);
+    }
+}
@@ -6,9 +6,9 @@ pub use combinator::*;
 pub use parser::*;
 pub use tuple::*;
 
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseError {
     TagNotFound,
     NoValueFound,
@@ -17,6 +17,19 @@ pub enum ParseError {
     Unknown,
 }
 
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ParseError::TagNotFound => "expected tag not found",
+            ParseError::NoValueFound => "no value found",
+            ParseError::UnexpectedEnd => "unexpected end of input",
+            ParseError::MapError => "value could not be converted",
+            ParseError::Unknown => "unknown error",
+        };
+        f.write_str(message)
+    }
+}
+
 pub type Res<I, O> = Result<(I, O), ParseError>;
 
 pub trait Parser<I> {
@@ -46,7 +59,7 @@ pub trait Input: Sized {
     }
 }
 
-impl<'a> Input for &'a str {
+impl Input for &str {
     type Item = char;
 
     fn split_item(&self) -> Res<Self::Item, Self> {
@@ -83,7 +96,7 @@ impl<'a> Input for &'a str {
                 if self.is_empty() {
                     Err(ParseError::UnexpectedEnd)
                 } else {
-                    Ok((self, &""))
+                    Ok((self, ""))
                 }
             }
         }
@@ -97,7 +110,7 @@ impl<'a> Input for &'a str {
         match self.find(predicate) {
             Some(i) => Ok(self.split(i)),
             // None => Ok((&self[self.len()..], &self[..self.len()]))
-            None => Ok((self, &"")),
+            None => Ok((self, "")),
         }
     }
 
@@ -106,7 +119,7 @@ impl<'a> Input for &'a str {
     }
 }
 
-impl<'a> Input for &'a [u8] {
+impl Input for &[u8] {
     type Item = u8;
 
     fn take(&self, count: usize) -> Self {
@@ -195,7 +208,7 @@ pub trait ParseTo<T> {
     fn parse_to(&self) -> Option<T>;
 }
 
-impl<'a, T: FromStr> ParseTo<T> for &'a str {
+impl<T: FromStr> ParseTo<T> for &str {
     fn parse_to(&self) -> Option<T> {
         self.parse().ok()
     }
@@ -0,0 +1,204 @@
+//! Timing spans inside solutions.
+//!
+//! Solutions mark the sections they want timed with `let _span = span("name");`, and the runner
+//! collects them per part into a tree of [`Node`]s. Without the `spans` feature a span is an empty
+//! guard and collecting returns no nodes, so the calls compile to nothing.
+
+use std::time::Duration;
+
+/// A named section of a part, with the sections nested in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: &'static str,
+    /// The time spent in the section over all of its calls.
+    pub time: Duration,
+    pub calls: u64,
+    pub children: Vec<Node>,
+}
+
+#[cfg(feature = "spans")]
+mod collector {
+    use std::{cell::RefCell, time::Duration};
+
+    use super::Node;
+
+    thread_local! {
+        pub static CURRENT: RefCell<Option<Collector>> = const { RefCell::new(None) };
+    }
+
+    /// The tree of spans entered so far, and the path to the innermost open one.
+    #[derive(Default)]
+    pub struct Collector {
+        pub roots: Vec<Node>,
+        path: Vec<usize>,
+    }
+
+    impl Collector {
+        fn open(&mut self) -> &mut Vec<Node> {
+            let mut nodes = &mut self.roots;
+            for &i in &self.path {
+                nodes = &mut nodes[i].children;
+            }
+            nodes
+        }
+
+        pub fn enter(&mut self, name: &'static str) {
+            let nodes = self.open();
+            let i = match nodes.iter().position(|node| node.name == name) {
+                Some(i) => i,
+                None => {
+                    nodes.push(Node {
+                        name,
+                        time: Duration::ZERO,
+                        calls: 0,
+                        children: Vec::new(),
+                    });
+                    nodes.len() - 1
+                }
+            };
+            nodes[i].calls += 1;
+            self.path.push(i);
+        }
+
+        pub fn exit(&mut self, time: Duration) {
+            if let Some(i) = self.path.pop() {
+                self.open()[i].time += time;
+            }
+        }
+    }
+}
+
+/// Times a section of a part until it is dropped.
+#[cfg(feature = "spans")]
+pub struct Span {
+    /// When the span was entered, or `None` when nothing is collecting spans.
+    start: Option<std::time::Instant>,
+}
+
+#[cfg(feature = "spans")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let time = start.elapsed();
+        collector::CURRENT.with(|current| {
+            if let Some(collector) = current.borrow_mut().as_mut() {
+                collector.exit(time);
+            }
+        });
+    }
+}
+
+/// Enters a section called `name`, nested in the sections that are open on this thread.
+#[cfg(feature = "spans")]
+pub fn span(name: &'static str) -> Span {
+    let collecting = collector::CURRENT.with(|current| match current.borrow_mut().as_mut() {
+        Some(collector) => {
+            collector.enter(name);
+            true
+        }
+        None => false,
+    });
+    Span {
+        start: collecting.then(std::time::Instant::now),
+    }
+}
+
+/// Puts back the collector that was installed before [`collect`], even when `f` panics.
+#[cfg(feature = "spans")]
+struct Restore(Option<collector::Collector>);
+
+#[cfg(feature = "spans")]
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        collector::CURRENT.with(|current| current.replace(previous));
+    }
+}
+
+/// Runs `f` and returns the spans it entered on this thread.
+#[cfg(feature = "spans")]
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Vec<Node>) {
+    let previous =
+        collector::CURRENT.with(|current| current.replace(Some(collector::Collector::default())));
+    let restore = Restore(previous);
+    let result = f();
+    let collector = collector::CURRENT.with(|current| current.take());
+    drop(restore);
+    let nodes = collector.map(|collector| collector.roots);
+    (result, nodes.unwrap_or_default())
+}
+
+/// Times a section of a part until it is dropped.
+#[cfg(not(feature = "spans"))]
+pub struct Span;
+
+/// Enters a section called `name`, nested in the sections that are open on this thread.
+#[cfg(not(feature = "spans"))]
+#[inline(always)]
+pub fn span(_name: &'static str) -> Span {
+    Span
+}
+
+/// Runs `f` and returns the spans it entered on this thread.
+#[cfg(not(feature = "spans"))]
+#[inline(always)]
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Vec<Node>) {
+    (f(), Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work() {
+        let _outer = span("outer");
+        for _ in 0..3 {
+            let _inner = span("inner");
+        }
+    }
+
+    #[cfg(feature = "spans")]
+    #[test]
+    fn tree() {
+        let ((), nodes) = collect(|| {
+            work();
+            work();
+            let _other = span("other");
+        });
+        let shape = |nodes: &[Node]| {
+            nodes
+                .iter()
+                .map(|node| (node.name, node.calls))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(shape(&nodes), [("outer", 2), ("other", 1)]);
+        assert_eq!(shape(&nodes[0].children), [("inner", 6)]);
+        assert!(nodes[0].time >= nodes[0].children[0].time);
+    }
+
+    #[cfg(feature = "spans")]
+    #[test]
+    fn panic_in_collect() {
+        let caught = crate::isolate::catch(|| collect(|| -> () { panic!("in a span") }));
+        assert!(caught.is_err());
+        collector::CURRENT.with(|current| assert!(current.borrow().is_none()));
+        let ((), nodes) = collect(work);
+        assert_eq!(nodes.len(), 1);
+    }
+
+    #[cfg(not(feature = "spans"))]
+    #[test]
+    fn disabled() {
+        assert_eq!(std::mem::size_of::<Span>(), 0);
+        assert_eq!(collect(work), ((), Vec::new()));
+    }
+
+    #[test]
+    fn outside_collect() {
+        work();
+        let ((), nodes) = collect(|| ());
+        assert!(nodes.is_empty());
+    }
+}
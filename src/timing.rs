@@ -0,0 +1,101 @@
+use std::{fmt::Display, time::Duration};
+
+/// Formats a duration with a unit that keeps the number readable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(pub Duration);
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos();
+        let secs = self.0.as_secs_f64();
+        if nanos < 1_000 {
+            write!(f, "{}ns", nanos)
+        } else if nanos < 1_000_000 {
+            write!(f, "{:.2}µs", secs * 1e6)
+        } else if nanos < 1_000_000_000 {
+            write!(f, "{:.2}ms", secs * 1e3)
+        } else {
+            write!(f, "{:.2}s", secs)
+        }
+    }
+}
+
+/// Summary statistics over repeated timings of the same part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+        let median = if runs % 2 == 1 {
+            sorted[runs / 2]
+        } else {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        };
+
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / runs as f64;
+        let variance = nanos.map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, mean {}, σ {} ({} runs)",
+            Elapsed(self.min),
+            Elapsed(self.median),
+            Elapsed(self.mean),
+            Elapsed(self.std_dev),
+            self.runs
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Elapsed, Stats};
+
+    #[test]
+    fn units() {
+        assert_eq!(Elapsed(Duration::from_nanos(999)).to_string(), "999ns");
+        assert_eq!(Elapsed(Duration::from_nanos(12_346)).to_string(), "12.35µs");
+        assert_eq!(Elapsed(Duration::from_micros(1_500)).to_string(), "1.50ms");
+        assert_eq!(Elapsed(Duration::from_millis(2_250)).to_string(), "2.25s");
+    }
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2_500));
+        assert_eq!(stats.mean, Duration::from_nanos(2_500));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1_118));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! Structural checks of puzzle inputs, run by `aoc23 check-input`.
+//!
+//! A validator looks for everything a solution takes for granted about its input and reports each
+//! problem with its position, instead of the solution panicking at the first one.
+
+use std::fmt::Display;
+
+/// A validator, which returns every problem it finds in a normalized input.
+pub type Validator = fn(&str) -> Vec<Violation>;
+
+/// A problem with an input, at a line and column counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// The lines of an input with their line numbers.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// The characters of a line with their columns.
+pub fn columns(line: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    line.chars().enumerate().map(|(i, ch)| (i + 1, ch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let violation = lines("ab\ncd")
+            .flat_map(|(line, text)| {
+                columns(text)
+                    .filter(|&(_, ch)| ch == 'd')
+                    .map(move |(column, _)| Violation::new(line, column, "no d"))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(violation, [Violation::new(2, 2, "no d")]);
+        assert_eq!(violation[0].to_string(), "line 2, column 2: no d");
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{example::Example, Info, Part, Puzzle, PuzzleError};
+
+fn no_digit(line: usize) -> PuzzleError {
+    PuzzleError::invalid(format!("line {} contains no digit", line + 1))
+}
+
+fn calibrate(lines: &[String]) -> Result<u32, PuzzleError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut iter = line.chars().filter_map(|ch| ch.to_digit(10));
+
+            let first = iter.next().ok_or_else(|| no_digit(i))?;
+            let second = iter.next_back().unwrap_or(first);
+            Ok(first * 10 + second)
+        })
+        .sum()
+}
+
+fn starts_with_digit(v: &str) -> Option<u32> {
+    Some(match v {
+        v if v.starts_with("one") => 1,
+        v if v.starts_with("two") => 2,
+        v if v.starts_with("three") => 3,
+        v if v.starts_with("four") => 4,
+        v if v.starts_with("five") => 5,
+        v if v.starts_with("six") => 6,
+        v if v.starts_with("seven") => 7,
+        v if v.starts_with("eight") => 8,
+        v if v.starts_with("nine") => 9,
+        _ => return v.chars().next().and_then(|ch| ch.to_digit(10)),
+    })
+}
+
+struct DigitIterator<'a> {
+    line: &'a str,
+}
+
+impl<'a> Iterator for DigitIterator<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.line.is_empty() {
+            let digit = starts_with_digit(self.line);
+            self.line = &self.line[1..];
+            if digit.is_some() {
+                return digit;
+            }
+        }
+        None
+    }
+}
+
+fn calibrate2(lines: &[String]) -> Result<u32, PuzzleError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut iter = DigitIterator { line };
+            let first = iter.next().ok_or_else(|| no_digit(i))?;
+            let last = iter.last().unwrap_or(first);
+            Ok(first * 10 + last)
+        })
+        .sum()
+}
+
+pub struct Day1;
+
+impl Puzzle for Day1 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 1,
+        title: "Trebuchet?!",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        calibrate(lines)
+    }
+
+    fn part2(lines: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        calibrate2(lines)
+    }
+}
+
+const EXAMPLE1: &str = include_str!("../input/2023/day1.ex1");
+
+const EXAMPLE2: &str = include_str!("../input/2023/day1.ex2");
+
+crate::examples! {
+    Day1 {
+        example1: One(EXAMPLE1) => 142,
+        example2: Two(EXAMPLE2) => 281,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2023::day1::Day1;
+    use crate::{Part, Puzzle, PuzzleError};
+
+    #[test]
+    fn missing_digit() {
+        assert_eq!(
+            Day1::solve("1abc2\nabc", Part::One),
+            Err(PuzzleError::invalid("line 2 contains no digit"))
+        );
+    }
+}
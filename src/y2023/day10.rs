@@ -0,0 +1,452 @@
+use crate::{
+    example::Example,
+    validate::{self, Violation},
+    Info, Part, Puzzle, PuzzleError, Variant,
+};
+use glam::UVec2;
+use itertools::Itertools;
+use std::{
+    collections::HashSet,
+    ops::{Index, IndexMut},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn step(self, pos: UVec2) -> Option<UVec2> {
+        match self {
+            Direction::Up => pos.y.checked_sub(1).map(|y| UVec2::new(pos.x, y)),
+            Direction::Down => pos.y.checked_add(1).map(|y| UVec2::new(pos.x, y)),
+            Direction::Left => pos.x.checked_sub(1).map(|x| UVec2::new(x, pos.y)),
+            Direction::Right => pos.x.checked_add(1).map(|x| UVec2::new(x, pos.y)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Some(Direction, Direction),
+    Start,
+    None,
+}
+
+impl Tile {
+    pub fn from_char(ch: char) -> Result<Self, PuzzleError> {
+        Ok(match ch {
+            '|' => Self::Some(Direction::Up, Direction::Down),
+            'J' => Self::Some(Direction::Up, Direction::Left),
+            'L' => Self::Some(Direction::Up, Direction::Right),
+            '7' => Self::Some(Direction::Left, Direction::Down),
+            'F' => Self::Some(Direction::Right, Direction::Down),
+            '-' => Self::Some(Direction::Left, Direction::Right),
+            '.' => Self::None,
+            'S' => Self::Start,
+            _ => return Err(PuzzleError::invalid(format!("invalid tile: {:?}", ch))),
+        })
+    }
+
+    pub fn adjacent(self, pos: UVec2) -> Option<(UVec2, UVec2)> {
+        match self {
+            Tile::Some(a, b) => {
+                let a = a.step(pos)?;
+                let b = b.step(pos)?;
+                Some((a, b))
+            }
+            Tile::Start => None,
+            Tile::None => None,
+        }
+    }
+
+    pub fn connects_to(self, pos: UVec2, other: UVec2) -> bool {
+        let Some((a, b)) = self.adjacent(pos) else {
+            return false;
+        };
+        a == other || b == other
+    }
+
+    pub fn matches(self, (x, y): (Direction, Direction)) -> bool {
+        if let Self::Some(a, b) = self {
+            (a == x && b == y) || (b == x && a == y)
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_iter(iter: impl Iterator<Item = T>, width: usize) -> Result<Self, PuzzleError> {
+        let data = iter.collect::<Vec<_>>();
+        if data.is_empty() {
+            return Err(PuzzleError::invalid("the grid is empty"));
+        }
+        if width == 0 || data.len() % width != 0 {
+            return Err(PuzzleError::invalid("the tiles don't fill a rectangle"));
+        }
+        let height = data.len() / width;
+        Ok(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        (y * self.width) + x
+    }
+
+    /// The cell at `pos`, or `None` when it's outside the grid.
+    fn get(&self, pos: UVec2) -> Option<&T> {
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        (x < self.width && y < self.height).then(|| &self.data[self.index(x, y)])
+    }
+
+    fn find<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<UVec2> {
+        let idx = self.data.iter().position(predicate)?;
+        let x = idx % self.width;
+        let y = idx / self.width;
+        Some(UVec2::new(x as u32, y as u32))
+    }
+
+    fn walk_diagonal(&self, from: UVec2) -> impl Iterator<Item = UVec2> {
+        let dx = self.width as u32 - from.x;
+        let dy = self.height as u32 - from.y;
+        (0..dx.min(dy)).map(move |i| from + UVec2::new(i, i))
+    }
+
+    fn iter_positions(&self) -> impl Iterator<Item = UVec2> {
+        (0..self.width)
+            .cartesian_product(0..self.height)
+            .map(|(x, y)| UVec2::new(x as u32, y as u32))
+    }
+}
+
+impl<T> Index<(u32, u32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (u32, u32)) -> &Self::Output {
+        &self.data[self.index(x as usize, y as usize)]
+    }
+}
+
+impl<T> IndexMut<(u32, u32)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (u32, u32)) -> &mut Self::Output {
+        let idx = self.index(x as usize, y as usize);
+        &mut self.data[idx]
+    }
+}
+
+pub struct Maze {
+    grid: Grid<Tile>,
+}
+
+/// The grid with the start replaced by the pipe it stands on, and the positions of the loop.
+fn main_loop(maze: &Maze) -> Result<(Grid<Tile>, HashSet<UVec2>), PuzzleError> {
+    let mut grid = maze.grid.clone();
+    let start = grid
+        .find(|t| matches!(t, Tile::Start))
+        .ok_or_else(|| PuzzleError::invalid("the grid has no start tile"))?;
+
+    let neighbors = [
+        Direction::Up,
+        Direction::Left,
+        Direction::Right,
+        Direction::Down,
+    ]
+    .into_iter()
+    .filter_map(|dir| {
+        let nb = dir.step(start)?;
+        let tile = grid.get(nb)?;
+        tile.connects_to(nb, start).then_some((dir, nb))
+    })
+    .collect::<Vec<_>>();
+    let [(dir_a, start_a), (dir_b, _)] = neighbors[..] else {
+        return Err(PuzzleError::invalid(format!(
+            "the start tile connects to {} pipes instead of 2",
+            neighbors.len()
+        )));
+    };
+
+    grid[start.into()] = Tile::Some(dir_a, dir_b);
+
+    let mut pipes = HashSet::new();
+    pipes.insert(start);
+
+    let mut prev = start;
+    let mut pos = start_a;
+    while pos != start {
+        pipes.insert(pos);
+        let Some((left, right)) = grid
+            .get(pos)
+            .and_then(|tile| tile.adjacent(pos))
+            .filter(|&(left, right)| left == prev || right == prev)
+        else {
+            return Err(PuzzleError::invalid(format!(
+                "the loop breaks off at x {}, y {}",
+                pos.x, pos.y
+            )));
+        };
+        let next = if left != prev { left } else { right };
+
+        prev = pos;
+        pos = next;
+    }
+
+    Ok((grid, pipes))
+}
+
+/// Part 2 by flood-filling the outside of the loop on a grid of twice the resolution, where the
+/// gaps between pipes that don't connect become cells the fill can squeeze through.
+fn flood_fill(maze: &Maze) -> Result<usize, PuzzleError> {
+    let (grid, pipes) = main_loop(maze)?;
+    let (width, height) = (grid.width * 2 + 1, grid.height * 2 + 1);
+    let fine = |pos: UVec2| (pos.x as usize * 2 + 1, pos.y as usize * 2 + 1);
+
+    let mut walls = vec![false; width * height];
+    for &pos in &pipes {
+        let (x, y) = fine(pos);
+        walls[y * width + x] = true;
+        if let Tile::Some(a, b) = grid[(pos.x, pos.y)] {
+            for direction in [a, b] {
+                let (x, y) = match direction {
+                    Direction::Up => (x, y - 1),
+                    Direction::Down => (x, y + 1),
+                    Direction::Left => (x - 1, y),
+                    Direction::Right => (x + 1, y),
+                };
+                walls[y * width + x] = true;
+            }
+        }
+    }
+
+    let mut outside = vec![false; width * height];
+    outside[0] = true;
+    let mut stack = vec![(0usize, 0usize)];
+    while let Some((x, y)) = stack.pop() {
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (x, y) in neighbors {
+            if x < width && y < height && !walls[y * width + x] && !outside[y * width + x] {
+                outside[y * width + x] = true;
+                stack.push((x, y));
+            }
+        }
+    }
+
+    Ok(grid
+        .iter_positions()
+        .filter(|pos| !pipes.contains(pos))
+        .filter(|&pos| {
+            let (x, y) = fine(pos);
+            !outside[y * width + x]
+        })
+        .count())
+}
+
+/// Checks that the grid is rectangular, holds only known tiles and has exactly one start.
+fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut starts = Vec::new();
+    let mut width = None;
+    for (line, text) in validate::lines(input) {
+        let len = text.chars().count();
+        let expected = *width.get_or_insert(len);
+        if len != expected {
+            violations.push(Violation::new(
+                line,
+                len.min(expected) + 1,
+                format!("row is {} tiles wide instead of {}", len, expected),
+            ));
+        }
+        for (column, ch) in validate::columns(text) {
+            match ch {
+                'S' => starts.push((line, column)),
+                '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' => {}
+                _ => violations.push(Violation::new(
+                    line,
+                    column,
+                    format!("invalid tile {:?}", ch),
+                )),
+            }
+        }
+    }
+    match starts.as_slice() {
+        [] => violations.push(Violation::new(1, 1, "the grid has no start tile")),
+        [(first_line, first_column), others @ ..] => {
+            for &(line, column) in others {
+                violations.push(Violation::new(
+                    line,
+                    column,
+                    format!(
+                        "another start tile besides the one at line {}, column {}",
+                        first_line, first_column
+                    ),
+                ));
+            }
+        }
+    }
+    violations.sort_by_key(|violation| (violation.line, violation.column));
+    violations
+}
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 10,
+        title: "Pipe Maze",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Maze;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const VALIDATOR: Option<validate::Validator> = Some(validate);
+    const PART2_VARIANTS: &'static [Variant<Self::Parsed, Self::Output2>] = &[Variant {
+        name: "flood-fill",
+        solve: flood_fill,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        for (row, line) in input.lines().enumerate() {
+            let len = line.chars().count();
+            if len != width {
+                return Err(PuzzleError::invalid(format!(
+                    "row {} is {} tiles wide instead of {}",
+                    row + 1,
+                    len,
+                    width
+                )));
+            }
+        }
+        let tiles = input
+            .lines()
+            .flat_map(str::chars)
+            .map(Tile::from_char)
+            .collect::<Result<Vec<_>, _>>()?;
+        let grid = Grid::from_iter(tiles.into_iter(), width)?;
+        Ok(Maze { grid })
+    }
+
+    fn part1(maze: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        let (_, pipes) = main_loop(maze)?;
+        Ok(pipes.len() / 2)
+    }
+
+    fn part2(maze: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let (grid, pipes) = main_loop(maze)?;
+        Ok(grid
+            .iter_positions()
+            .filter(|pos| !pipes.contains(pos))
+            .filter(|pos| {
+                let mut count = 0;
+
+                for p in grid.walk_diagonal(*pos) {
+                    let current = grid[(p.x, p.y)];
+                    if pipes.contains(&p)
+                        && !current.matches((Direction::Up, Direction::Right))
+                        && !current.matches((Direction::Left, Direction::Down))
+                    {
+                        count += 1;
+                    }
+                }
+
+                count % 2 == 1
+            })
+            .count())
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day10.ex1");
+
+const EXAMPLE2: &str = include_str!("../input/2023/day10.ex2");
+
+const EXAMPLE3: &str = include_str!("../input/2023/day10.ex3");
+
+crate::examples! {
+    Day10 {
+        example1: One(EXAMPLE) => 8,
+        example2: Two(EXAMPLE2) => 8,
+        example3: Two(EXAMPLE3) => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flood_fill, validate, Day10, EXAMPLE, EXAMPLE2, EXAMPLE3};
+    use crate::{validate::Violation, Part, Puzzle, PuzzleError};
+
+    #[test]
+    fn flood_fill_variant() {
+        assert_eq!(flood_fill(&Day10::parse(EXAMPLE2).unwrap()), Ok(8));
+        assert_eq!(flood_fill(&Day10::parse(EXAMPLE3).unwrap()), Ok(4));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let solve = |input| Day10::solve(input, Part::One);
+        assert_eq!(
+            solve("S-x\n"),
+            Err(PuzzleError::invalid("invalid tile: 'x'"))
+        );
+        assert_eq!(solve(""), Err(PuzzleError::invalid("the grid is empty")));
+        assert_eq!(
+            solve("S-7\n|.\n"),
+            Err(PuzzleError::invalid("row 2 is 2 tiles wide instead of 3"))
+        );
+        assert_eq!(
+            solve("F-7\n|.|\nL-J\n"),
+            Err(PuzzleError::invalid("the grid has no start tile"))
+        );
+        assert_eq!(
+            solve("S-7\n..|\n.-J\n"),
+            Err(PuzzleError::invalid(
+                "the start tile connects to 1 pipes instead of 2"
+            ))
+        );
+        assert_eq!(
+            solve("S-7\n|.|\nL.J\n"),
+            Err(PuzzleError::invalid("the loop breaks off at x 1, y 2"))
+        );
+    }
+
+    #[test]
+    fn validator() {
+        assert_eq!(validate(EXAMPLE), []);
+        assert_eq!(
+            validate("S-7\n|.\nL-S\nLxJ"),
+            [
+                Violation::new(2, 3, "row is 2 tiles wide instead of 3"),
+                Violation::new(
+                    3,
+                    3,
+                    "another start tile besides the one at line 1, column 1"
+                ),
+                Violation::new(4, 2, "invalid tile 'x'"),
+            ]
+        );
+        assert_eq!(
+            validate("F7\nLJ"),
+            [Violation::new(1, 1, "the grid has no start tile")]
+        );
+    }
+}
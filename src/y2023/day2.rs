@@ -1,4 +1,4 @@
-use crate::Puzzle;
+use crate::{example::Example, Info, Part, Puzzle, PuzzleError};
 
 #[derive(Debug, Default, Clone, Copy)]
 struct Bag {
@@ -44,42 +44,58 @@ fn tag<'a>(tag: &str, input: &'a str) -> Option<&'a str> {
     (tag == expected).then(|| &input[tag.len()..])
 }
 
-fn parse_game(input: &str) -> Game {
-    let (prefix, game) = input.split_once(':').unwrap();
-    let number = tag("Game ", prefix).unwrap().parse::<u32>().unwrap();
+fn parse_game(input: &str) -> Result<Game, PuzzleError> {
+    let invalid = || PuzzleError::invalid(format!("invalid game: {:?}", input));
+    let (prefix, game) = input.split_once(':').ok_or_else(invalid)?;
+    let number = tag("Game ", prefix)
+        .and_then(|number| number.parse::<u32>().ok())
+        .ok_or_else(invalid)?;
     let mut pulls = Vec::new();
     for pull in game.split(';') {
         let mut bag = Bag::default();
         for pair in pull.trim().split(", ") {
-            let (count, color) = pair.split_once(' ').unwrap();
-            let count = count.parse::<u32>().unwrap();
+            let (count, color) = pair.split_once(' ').ok_or_else(invalid)?;
+            let count = count.parse::<u32>().map_err(|_| invalid())?;
             *match color {
                 "red" => &mut bag.red,
                 "green" => &mut bag.green,
                 "blue" => &mut bag.blue,
-                _ => panic!(),
+                _ => return Err(invalid()),
             } = count;
         }
         pulls.push(bag);
     }
 
-    Game { number, pulls }
+    Ok(Game { number, pulls })
 }
 
 pub struct Day2;
 
 impl Puzzle for Day2 {
-    type Output = u32;
+    const INFO: Info = Info {
+        year: 2023,
+        day: 2,
+        title: "Cube Conundrum",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Vec<Game>;
+    type Output1 = u32;
+    type Output2 = u32;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        input.lines().map(parse_game).collect()
+    }
 
-    fn part1(input: &str) -> Self::Output {
+    fn part1(games: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
         let reference = Bag {
             red: 12,
             green: 13,
             blue: 14,
         };
 
-        let games: Vec<Game> = input.lines().map(parse_game).collect();
-        games
+        Ok(games
             .iter()
             .filter_map(|game| {
                 game.pulls
@@ -87,33 +103,19 @@ impl Puzzle for Day2 {
                     .all(|bag| reference.can_contain(bag))
                     .then_some(game.number)
             })
-            .sum()
+            .sum())
     }
 
-    fn part2(input: &str) -> Self::Output {
-        let games: Vec<Game> = input.lines().map(parse_game).collect();
-        games.iter().map(|game| game.minimum_bag().power()).sum()
+    fn part2(games: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        Ok(games.iter().map(|game| game.minimum_bag().power()).sum())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::Day2;
-    use crate::Puzzle;
-
-    const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
-
-    #[test]
-    fn example1() {
-        assert_eq!(Day2::part1(INPUT), 8);
-    }
+const EXAMPLE: &str = include_str!("../input/2023/day2.ex1");
 
-    #[test]
-    fn example2() {
-        assert_eq!(Day2::part2(INPUT), 2286)
+crate::examples! {
+    Day2 {
+        example1: One(EXAMPLE) => 8,
+        example2: Two(EXAMPLE) => 2286,
     }
 }
@@ -1,13 +1,13 @@
 use std::ops::Range;
 
-use crate::Puzzle;
+use crate::{example::Example, Info, Puzzle, PuzzleError};
 
 fn extend_bounds(bounds: Range<usize>) -> Range<usize> {
     (bounds.start.saturating_sub(1))..(bounds.end + 1)
 }
 
 #[derive(Debug)]
-enum Part {
+pub enum Part {
     Symbol { _symbol: char, index: usize },
     Number { number: u32, bounds: Range<usize> },
 }
@@ -18,7 +18,7 @@ struct SchematicIterator<'a> {
 }
 
 impl<'a> Iterator for SchematicIterator<'a> {
-    type Item = Part;
+    type Item = Result<Part, PuzzleError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         for i in self.idx..self.line.len() {
@@ -29,10 +29,12 @@ impl<'a> Iterator for SchematicIterator<'a> {
                 while offset < self.line.len() && self.line[offset].is_ascii_digit() {
                     offset += 1;
                 }
-                let number = std::str::from_utf8(&self.line[i..offset])
-                    .unwrap()
-                    .parse::<u32>()
-                    .unwrap();
+                let digits = String::from_utf8_lossy(&self.line[i..offset]);
+                let Ok(number) = digits.parse::<u32>() else {
+                    self.idx = offset;
+                    let error = format!("number {} is too large", digits);
+                    return Some(Err(PuzzleError::invalid(error)));
+                };
                 let bounds = i..offset;
                 self.idx = offset;
                 Part::Number { number, bounds }
@@ -45,19 +47,19 @@ impl<'a> Iterator for SchematicIterator<'a> {
                 }
             };
 
-            return Some(part);
+            return Some(Ok(part));
         }
         None
     }
 }
 
 #[derive(Debug)]
-struct Schematic {
+pub struct Schematic {
     rows: Vec<Vec<Part>>,
 }
 
 impl Schematic {
-    pub fn parse(input: &str) -> Self {
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
         let rows = input
             .lines()
             .map(|line| {
@@ -67,9 +69,9 @@ impl Schematic {
                 };
                 iter.collect()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self { rows }
+        Ok(Self { rows })
     }
 
     pub fn iter_adjacent_rows(&self, row: usize) -> impl Iterator<Item = &Part> {
@@ -94,12 +96,12 @@ impl Schematic {
         false
     }
 
-    pub fn get_ratio(&self, row: usize, index: usize) -> Option<u32> {
-        let adjacent_numbers: Vec<u32> = self
+    pub fn get_ratio(&self, row: usize, index: usize) -> Option<u64> {
+        let adjacent_numbers: Vec<u64> = self
             .iter_adjacent_rows(row)
             .filter_map(|part| {
                 if let Part::Number { number, bounds } = part {
-                    Some((*number, bounds))
+                    Some((u64::from(*number), bounds))
                 } else {
                     None
                 }
@@ -122,64 +124,78 @@ impl Schematic {
 pub struct Day3;
 
 impl Puzzle for Day3 {
-    type Output = u32;
+    const INFO: Info = Info {
+        year: 2023,
+        day: 3,
+        title: "Gear Ratios",
+        parts: &[crate::Part::One, crate::Part::Two],
+    };
+
+    type Parsed = Schematic;
+    type Output1 = u64;
+    type Output2 = u64;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        Schematic::parse(input)
+    }
 
-    fn part1(input: &str) -> Self::Output {
-        let schematic = Schematic::parse(input);
+    fn part1(schematic: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
         let mut total = 0;
         for (i, row) in schematic.rows.iter().enumerate() {
             for part in row {
                 if let Part::Number { number, bounds } = part {
                     if schematic.has_adjacent_symbol(i, bounds.clone()) {
-                        total += *number;
+                        total += u64::from(*number);
                     }
                 }
             }
         }
 
-        total
+        Ok(total)
     }
 
-    fn part2(input: &str) -> Self::Output {
-        let schematic = Schematic::parse(input);
-        let mut total = 0;
+    fn part2(schematic: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let mut total: u64 = 0;
         for (i, row) in schematic.rows.iter().enumerate() {
             for part in row {
                 if let Part::Symbol { index, .. } = part {
                     if let Some(ratio) = schematic.get_ratio(i, *index) {
-                        total += ratio;
+                        total = total.checked_add(ratio).ok_or_else(|| {
+                            PuzzleError::invalid("the sum of the gear ratios overflows")
+                        })?;
                     }
                 }
             }
         }
 
-        total
+        Ok(total)
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day3.ex1");
+
+crate::examples! {
+    Day3 {
+        example1: One(EXAMPLE) => 4361,
+        example2: Two(EXAMPLE) => 467835,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Day3;
-    use crate::Puzzle;
-
-    const INPUT: &str = r#"467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."#;
-
-    #[test]
-    fn example1() {
-        assert_eq!(Day3::part1(INPUT), 4361);
-    }
+    use crate::{Part, Puzzle, PuzzleError};
 
     #[test]
-    fn example2() {
-        assert_eq!(Day3::part2(INPUT), 467835);
+    fn large_numbers() {
+        assert_eq!(
+            Day3::solve("99999999999*\n", Part::One),
+            Err(PuzzleError::invalid("number 99999999999 is too large"))
+        );
+        assert_eq!(
+            Day3::solve("4000000000*3000000000\n", Part::Two),
+            Ok("12000000000000000000".to_string())
+        );
     }
 }
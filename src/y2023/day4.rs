@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use crate::{example::Example, Info, Part, Puzzle, PuzzleError};
+
+pub struct Card {
+    winners: HashSet<usize>,
+    numbers: HashSet<usize>,
+}
+
+impl Card {
+    pub fn parse(input: &str) -> Result<Self, PuzzleError> {
+        let invalid = || PuzzleError::invalid(format!("invalid card: {:?}", input));
+        let (winners, numbers) = input.split_once('|').ok_or_else(invalid)?;
+        let (_, winners) = winners.split_once(':').ok_or_else(invalid)?;
+        let winners = winners
+            .split_whitespace()
+            .map(|part| part.parse::<usize>().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        let numbers = numbers
+            .split_whitespace()
+            .map(|part| part.parse::<usize>().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { winners, numbers })
+    }
+
+    pub fn wins(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|num| self.winners.contains(*num))
+            .count()
+    }
+
+    pub fn score(&self) -> usize {
+        let count = self.wins();
+        if count > 0 {
+            2_usize.pow(count as u32 - 1)
+        } else {
+            0
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Puzzle for Day4 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 4,
+        title: "Scratchcards",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Vec<Card>;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        input.lines().map(Card::parse).collect()
+    }
+
+    fn part1(cards: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        Ok(cards.iter().map(Card::score).sum())
+    }
+
+    fn part2(cards: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let mut result = vec![1; cards.len()];
+
+        for (idx, card) in cards.iter().enumerate() {
+            for i in 1..=card.wins().min(cards.len() - 1) {
+                result[idx + i] += result[idx];
+            }
+        }
+
+        Ok(result.iter().sum())
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day4.ex1");
+
+crate::examples! {
+    Day4 {
+        example1: One(EXAMPLE) => 13,
+        example2: Two(EXAMPLE) => 30,
+    }
+}
@@ -1,14 +1,17 @@
 use std::fmt::Debug;
 
 use crate::{
+    cancel,
+    example::Example,
+    progress,
     slurp::{
         self, delimited, map, map_res, nl, opt, pair, separated_list, separated_pair, tag,
         take_while1, tuple, Res,
     },
-    Puzzle,
+    Info, Part, Puzzle, PuzzleError, Variant,
 };
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 struct Range {
     source: usize,
     destination: usize,
@@ -67,7 +70,7 @@ impl Range {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Map {
     ranges: Vec<Range>,
 }
@@ -91,6 +94,37 @@ impl Map {
         }
         value
     }
+
+    /// Maps whole ranges of values, splitting them where they cross the bounds of a range.
+    pub fn map_ranges(&self, values: &[std::ops::Range<usize>]) -> Vec<std::ops::Range<usize>> {
+        let mut mapped = Vec::new();
+        for values in values {
+            let mut start = values.start;
+            for range in &self.ranges {
+                let end = range.source + range.len;
+                if end <= start {
+                    continue;
+                }
+                if range.source >= values.end {
+                    break;
+                }
+                if range.source > start {
+                    mapped.push(start..range.source);
+                    start = range.source;
+                }
+                let end = end.min(values.end);
+                mapped.push(
+                    range.destination + (start - range.source)
+                        ..range.destination + (end - range.source),
+                );
+                start = end;
+            }
+            if start < values.end {
+                mapped.push(start..values.end);
+            }
+        }
+        mapped
+    }
 }
 
 fn num(input: &str) -> slurp::Res<&str, usize> {
@@ -162,77 +196,139 @@ fn parse_input(input: &str) -> Res<&str, (Vec<usize>, Vec<Map>)> {
     Ok((i, (seeds, maps)))
 }
 
-#[allow(clippy::type_complexity)]
-fn parse_input2(input: &str) -> Res<&str, (Vec<(usize, usize)>, Vec<Map>)> {
-    let (i, seeds) = parse_seeds2(input)?;
-    let (i, maps) = parse_maps(i)?;
+/// The seeds line read both ways: as single seeds for part 1 and as ranges for part 2.
+///
+/// The ranges keep their parse error, so an almanac with an odd number of seeds still solves
+/// part 1.
+pub struct Almanac {
+    seeds: Vec<usize>,
+    seed_ranges: Result<Vec<(usize, usize)>, PuzzleError>,
+    maps: Vec<Map>,
+}
 
-    Ok((i, (seeds, maps)))
+/// Part 2 by pushing the seed ranges through every map instead of searching the locations.
+fn ranges(almanac: &Almanac) -> Result<usize, PuzzleError> {
+    let seeds = almanac
+        .seed_ranges
+        .as_ref()
+        .map_err(Clone::clone)?
+        .iter()
+        .map(|&(start, len)| start..start + len)
+        .collect::<Vec<_>>();
+    almanac
+        .maps
+        .iter()
+        .fold(seeds, |values, map| map.map_ranges(&values))
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .ok_or(PuzzleError::NoSolution)
 }
 
 pub struct Day5;
 
 impl Puzzle for Day5 {
-    type Output = usize;
-
-    fn part1(input: &str) -> Self::Output {
-        let (_, (seeds, maps)) = parse_input(input).unwrap();
-        let min = seeds
-            .into_iter()
-            .map(|seed| {
-                let location = maps.iter().fold(seed, |value, map| map.map(value));
+    const INFO: Info = Info {
+        year: 2023,
+        day: 5,
+        title: "If You Give A Seed A Fertilizer",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Almanac;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const PART2_VARIANTS: &'static [Variant<Self::Parsed, Self::Output2>] = &[Variant {
+        name: "ranges",
+        solve: ranges,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        let (_, (seeds, maps)) = parse_input(input)?;
+        let seed_ranges = parse_seeds2(input).map(|(_, ranges)| ranges);
+        Ok(Almanac {
+            seeds,
+            seed_ranges: seed_ranges.map_err(Into::into),
+            maps,
+        })
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| {
+                let location = almanac.maps.iter().fold(seed, |value, map| map.map(value));
                 location
             })
             .min()
-            .unwrap();
-
-        min
+            .ok_or(PuzzleError::NoSolution)
     }
 
-    fn part2(input: &str) -> Self::Output {
-        let (_, (seeds, mut maps)) = parse_input2(input).unwrap();
-        let seeds = seeds
-            .into_iter()
-            .map(|(start, end)| start..start + end)
+    fn part2(almanac: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let seeds = almanac
+            .seed_ranges
+            .as_ref()
+            .map_err(Clone::clone)?
+            .iter()
+            .map(|&(start, end)| start..start + end)
             .collect::<Vec<_>>();
 
+        let mut maps = almanac.maps.clone();
         maps.reverse();
         maps.iter_mut().for_each(Map::invert);
 
         for loc in 0..10000000000 {
+            if loc % (1 << 20) == 0 {
+                cancel::check()?;
+                progress::set(loc as u64, 10000000000);
+            }
             let seed = maps.iter().fold(loc, |value, map| map.map(value));
             for range in &seeds {
                 if range.contains(&seed) {
-                    return loc;
+                    return Ok(loc);
                 }
             }
         }
-        panic!("No seed found.");
+        Err(PuzzleError::NoSolution)
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day5.ex1");
+
+crate::examples! {
+    Day5 {
+        example1: One(EXAMPLE) => 35,
+        example2: Two(EXAMPLE) => 46,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_seeds2, Day5};
-    use crate::Puzzle;
+    use super::{parse_seeds2, Day5, EXAMPLE};
+    use crate::{Part, Puzzle};
 
-    const INPUT: &str = include_str!("input/day5ex");
-    const INPUT2: &str = include_str!("input/day5");
+    const INPUT2: &str = include_str!("../input/2023/day5");
 
+    #[ignore]
     #[test]
-    fn example1() {
-        assert_eq!(Day5::part1(INPUT), 35);
+    fn solution2() {
+        assert_eq!(Day5::solve(INPUT2, Part::Two), Ok("59370572".to_string()));
     }
 
     #[test]
-    fn example2() {
-        assert_eq!(Day5::part2(INPUT), 46);
+    fn odd_seeds() {
+        let input = EXAMPLE.replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1);
+        assert_eq!(Day5::solve(&input, Part::One), Ok("43".to_string()));
+        assert!(Day5::solve(&input, Part::Two).is_err());
     }
 
-    #[ignore]
     #[test]
-    fn solution2() {
-        assert_eq!(Day5::part2(INPUT2), 59370572);
+    fn ranges_variant() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(super::ranges(&almanac), Ok(46));
     }
 
     #[test]
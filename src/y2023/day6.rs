@@ -0,0 +1,198 @@
+use crate::{
+    example::Example,
+    slurp::{self, map_res, opt, pair, separated_list, tag, take_while1, ParseError, Res},
+    Info, Part, Puzzle, PuzzleError,
+};
+
+#[derive(Debug)]
+struct Race {
+    time: usize,
+    distance: usize,
+}
+
+impl Race {
+    pub fn new(time: usize, distance: usize) -> Self {
+        Self { time, distance }
+    }
+}
+
+fn num(input: &str) -> slurp::Res<&str, usize> {
+    map_res(
+        take_while1(|ch: char| ch.is_ascii_digit()),
+        str::parse::<usize>,
+    )(input)
+}
+
+fn whitespace(input: &str) -> Res<&str, &str> {
+    take_while1(|ch| ch == ' ')(input)
+}
+
+fn nl(input: &str) -> slurp::Res<&str, ()> {
+    let (rem, _) = pair(opt(tag("\r")), tag("\n"))(input)?;
+    Ok((rem, ()))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (i, _) = pair(tag("Time:"), whitespace)(input)?;
+    let (i, times) = separated_list(num, whitespace)(i)?;
+    let (i, _) = nl(i)?;
+    let (i, _) = pair(tag("Distance:"), whitespace)(i)?;
+    let (_, distances) = separated_list(num, whitespace)(i)?;
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, dist)| Race::new(time, dist))
+        .collect())
+}
+
+fn parse_input2(input: &str) -> Result<Race, ParseError> {
+    let (i, _) = pair(tag("Time:"), whitespace)(input)?;
+    let (i, times) = take_while1(|ch| ch != '\n')(i)?;
+    let time: String = times.chars().filter(char::is_ascii_digit).collect();
+    let (i, _) = nl(i)?;
+    let (i, _) = pair(tag("Distance:"), whitespace)(i)?;
+    let (_, distances) = take_while1(|ch| ch != '\n')(i)?;
+    let distance: String = distances.chars().filter(char::is_ascii_digit).collect();
+    Ok(Race::new(
+        time.parse::<usize>().map_err(|_| ParseError::MapError)?,
+        distance
+            .parse::<usize>()
+            .map_err(|_| ParseError::MapError)?,
+    ))
+}
+
+fn roots(race: &Race) -> (f64, f64) {
+    let t = race.time as f64;
+    let d = race.distance as f64;
+    let base = t / 2.0;
+    let root = ((t / 2.0).powf(2.0) - d).sqrt();
+    let a = base - root;
+    let b = base + root;
+    (a.min(b), a.max(b))
+}
+
+/// The number of ways to beat the record, which is none when the roots are missing or too close.
+fn winning_times(race: &Race) -> usize {
+    let (low, high) = roots(race);
+    let low = low.floor() as usize + 1;
+    let high = high.ceil();
+    let Some(high) = (high as usize).checked_sub(1) else {
+        return 0;
+    };
+    let range = low..=high;
+    range.count()
+}
+
+/// The sheet read both ways: as separate races for part 1 and as one long race for part 2.
+///
+/// Each reading keeps its own error, so a sheet only one part can read still solves that part.
+pub struct Sheet {
+    races: Result<Vec<Race>, PuzzleError>,
+    race: Result<Race, PuzzleError>,
+}
+
+pub struct Day6;
+
+impl Puzzle for Day6 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 6,
+        title: "Wait For It",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Sheet;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        match (parse_input(input), parse_input2(input)) {
+            (Err(err), Err(_)) => Err(err.into()),
+            (races, race) => Ok(Sheet {
+                races: races.map_err(Into::into),
+                race: race.map_err(Into::into),
+            }),
+        }
+    }
+
+    fn part1(sheet: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        let races = sheet.races.as_ref().map_err(Clone::clone)?;
+        races
+            .iter()
+            .map(winning_times)
+            .try_fold(1usize, usize::checked_mul)
+            .ok_or_else(|| PuzzleError::invalid("the product of the ways to win overflows"))
+    }
+
+    fn part2(sheet: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let race = sheet.race.as_ref().map_err(Clone::clone)?;
+        Ok(winning_times(race))
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day6.ex1");
+
+crate::examples! {
+    Day6 {
+        example1: One(EXAMPLE) => 288,
+        example2: Two(EXAMPLE) => 71503,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day6;
+    use crate::{slurp::ParseError, Part, Puzzle, PuzzleError};
+
+    #[test]
+    fn unwinnable() {
+        assert_eq!(
+            Day6::solve("Time: 0\nDistance: 0\n", Part::One),
+            Ok("0".to_string())
+        );
+        assert_eq!(
+            Day6::solve("Time: 7 8\nDistance: 100 1\n", Part::One),
+            Ok("0".to_string())
+        );
+    }
+
+    #[test]
+    fn parts_parse_separately() {
+        // Part 2 reads the times as one number, which doesn't fit in a usize.
+        let input = format!(
+            "Time: {}\nDistance: {}\n",
+            ["9"; 20].join(" "),
+            ["0"; 20].join(" ")
+        );
+        assert_eq!(
+            Day6::solve(&input, Part::One),
+            Ok(8usize.pow(20).to_string())
+        );
+        assert_eq!(
+            Day6::solve(&input, Part::Two),
+            Err(PuzzleError::Parse(ParseError::MapError))
+        );
+        assert_eq!(
+            Day6::solve("Time: 7\n", Part::One),
+            Err(PuzzleError::Parse(ParseError::TagNotFound))
+        );
+    }
+
+    fn printfloat(f: f32) {
+        println!("{}", f);
+    }
+
+    #[test]
+    #[ignore]
+    fn what() {
+        let f: f32 = 44875631.0;
+        println!("{}", f);
+        println!("{:?}", f);
+        dbg!(&f);
+        printfloat(f);
+        let u: usize = unsafe { f.to_int_unchecked() };
+        assert_eq!(u, 44875630);
+    }
+}
@@ -0,0 +1,348 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{
+    example::Example,
+    slurp::{chr, map_res, separated_pair, tuple, ParseError, Res},
+    validate::{self, Violation},
+    Info, Part, Puzzle, PuzzleError,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Card {
+    Joker,
+    N2,
+    N3,
+    N4,
+    N5,
+    N6,
+    N7,
+    N8,
+    N9,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl Card {
+    pub fn from_char(ch: char) -> Result<Self, PuzzleError> {
+        Ok(match ch {
+            'A' => Card::A,
+            'K' => Card::K,
+            'Q' => Card::Q,
+            'J' => Card::J,
+            'T' => Card::T,
+            '9' => Card::N9,
+            '8' => Card::N8,
+            '7' => Card::N7,
+            '6' => Card::N6,
+            '5' => Card::N5,
+            '4' => Card::N4,
+            '3' => Card::N3,
+            '2' => Card::N2,
+            _ => return Err(PuzzleError::invalid(format!("invalid card: {:?}", ch))),
+        })
+    }
+
+    /// Reads jacks as jokers, as the part 2 rules do.
+    pub fn joker(self) -> Self {
+        if self == Card::J {
+            Card::Joker
+        } else {
+            self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeKind,
+    FullHouse,
+    FourKind,
+    FiveKind,
+}
+
+fn get_kind(mut cards: [Card; 5]) -> HandKind {
+    cards.sort();
+    cards.reverse();
+
+    let mut max = 0;
+    let mut pen = 0;
+    let mut count = 1;
+    let mut current = cards[0];
+
+    for card in cards.into_iter().skip(1) {
+        if current == card {
+            count += 1;
+        } else {
+            count = 1;
+            current = card;
+        }
+
+        if count > max {
+            max = count;
+        } else {
+            pen = pen.max(count);
+        }
+    }
+
+    match (max, pen) {
+        (5, _) => HandKind::FiveKind,
+        (4, _) => HandKind::FourKind,
+        (3, 2) => HandKind::FullHouse,
+        (3, _) => HandKind::ThreeKind,
+        (2, 2) => HandKind::TwoPair,
+        (2, _) => HandKind::OnePair,
+        _ => HandKind::HighCard,
+    }
+}
+
+fn get_kind2(mut cards: [Card; 5]) -> HandKind {
+    cards.sort();
+    cards.reverse();
+
+    let mut max = 1;
+    let mut pen = 1;
+    let mut count = 1;
+    let mut current = cards[0];
+    let mut jokers = if current == Card::Joker { 1 } else { 0 };
+
+    for card in cards.into_iter().skip(1) {
+        if card == Card::Joker {
+            jokers += 1;
+            continue;
+        } else if current == card {
+            count += 1;
+        } else {
+            count = 1;
+            current = card;
+        }
+
+        if count > max {
+            max = count;
+        } else {
+            pen = pen.max(count);
+        }
+    }
+
+    max = (max + jokers).min(5);
+
+    match (max, pen) {
+        (5, _) => HandKind::FiveKind,
+        (4, _) => HandKind::FourKind,
+        (3, 2) => HandKind::FullHouse,
+        (3, _) => HandKind::ThreeKind,
+        (2, 2) => HandKind::TwoPair,
+        (2, _) => HandKind::OnePair,
+        _ => HandKind::HighCard,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Hand {
+    cards: [Card; 5],
+    kind: HandKind,
+    bid: usize,
+}
+
+impl Hand {
+    pub fn new(cards: [Card; 5], bid: usize) -> Self {
+        Self {
+            cards,
+            kind: get_kind(cards),
+            bid,
+        }
+    }
+
+    pub fn new2(cards: [Card; 5], bid: usize) -> Self {
+        Self {
+            cards,
+            kind: get_kind2(cards),
+            bid,
+        }
+    }
+}
+
+fn parse<T: FromStr>(input: &str) -> Res<&str, T> {
+    let t = input.parse::<T>().map_err(|_| ParseError::MapError)?;
+    Ok(("", t))
+}
+
+fn card<'a>() -> impl FnMut(&'a str) -> Res<&'a str, Card> {
+    map_res(chr(), Card::from_char)
+}
+
+fn parse_cards(input: &str) -> Res<&str, [Card; 5]> {
+    let (i, (a, b, c, d, e)) = tuple((card(), card(), card(), card(), card()))(input)?;
+    Ok((i, [a, b, c, d, e]))
+}
+
+fn invalid_hand(input: &str) -> PuzzleError {
+    PuzzleError::invalid(format!("invalid hand: {:?}", input))
+}
+
+fn parse_hand(input: &str) -> Result<([Card; 5], usize), PuzzleError> {
+    let (_, (cards, bid)) =
+        separated_pair(parse_cards, ' ', parse::<usize>)(input).map_err(|_| invalid_hand(input))?;
+    Ok((cards, bid))
+}
+
+fn winnings(mut hands: Vec<Hand>) -> usize {
+    hands.sort_by(|left, right| {
+        let ordering = left.kind.cmp(&right.kind);
+        if let Ordering::Equal = ordering {
+            left.cards.cmp(&right.cards)
+        } else {
+            ordering
+        }
+    });
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| hand.bid * (i + 1))
+        .sum()
+}
+
+/// Checks that every line is a hand of five cards followed by a bid.
+fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (line, text) in validate::lines(input) {
+        let Some((cards, bid)) = text.split_once(' ') else {
+            violations.push(Violation::new(line, 1, "expected a hand and a bid"));
+            continue;
+        };
+        for (column, ch) in validate::columns(cards) {
+            if Card::from_char(ch).is_err() {
+                violations.push(Violation::new(
+                    line,
+                    column,
+                    format!("invalid card {:?}", ch),
+                ));
+            }
+        }
+        let count = cards.chars().count();
+        if count != 5 {
+            violations.push(Violation::new(
+                line,
+                1,
+                format!("hand has {} cards instead of 5", count),
+            ));
+        }
+        if bid.parse::<usize>().is_err() {
+            violations.push(Violation::new(
+                line,
+                count + 2,
+                format!("bid {:?} is not a number", bid),
+            ));
+        }
+    }
+    violations
+}
+
+pub struct Day7;
+
+impl Puzzle for Day7 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 7,
+        title: "Camel Cards",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Vec<Hand>;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const VALIDATOR: Option<validate::Validator> = Some(validate);
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        input
+            .lines()
+            .map(|line| parse_hand(line).map(|(cards, bid)| Hand::new(cards, bid)))
+            .collect()
+    }
+
+    fn part1(hands: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        Ok(winnings(hands.clone()))
+    }
+
+    fn part2(hands: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let hands = hands
+            .iter()
+            .map(|hand| Hand::new2(hand.cards.map(Card::joker), hand.bid))
+            .collect();
+        Ok(winnings(hands))
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day7.ex1");
+
+const EXAMPLE2: &str = include_str!("../input/2023/day7.ex2");
+
+crate::examples! {
+    Day7 {
+        example1: One(EXAMPLE) => 6440,
+        example2: Two(EXAMPLE) => 5905,
+        example3: Two(EXAMPLE2) => 6839,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_hand, validate, Card, Day7, Hand, HandKind, EXAMPLE};
+    use crate::{validate::Violation, y2023::day7::get_kind2, Part, Puzzle, PuzzleError};
+
+    #[test]
+    fn parse1() {
+        let (cards, bid) = parse_hand("K4444 123").unwrap();
+        let hand = Hand::new(cards, bid);
+        assert_eq!(
+            [Card::K, Card::N4, Card::N4, Card::N4, Card::N4],
+            hand.cards
+        );
+        assert_eq!(123, hand.bid);
+        assert_eq!(HandKind::FourKind, hand.kind);
+    }
+
+    #[test]
+    fn invalid_card() {
+        assert_eq!(
+            Day7::solve("32T3X 765", Part::One),
+            Err(PuzzleError::invalid("invalid hand: \"32T3X 765\""))
+        );
+    }
+
+    #[test]
+    fn jokers() {
+        assert_eq!(
+            get_kind2([Card::N2, Card::Joker, Card::Joker, Card::Joker, Card::Joker]),
+            HandKind::FiveKind
+        );
+    }
+
+    #[test]
+    fn order() {
+        assert!(Card::A > Card::K);
+        assert!(Card::A > Card::N2);
+        assert!(Card::N3 > Card::N2);
+        assert!(Card::Joker < Card::N2);
+    }
+
+    #[test]
+    fn validator() {
+        assert_eq!(validate(EXAMPLE), []);
+        assert_eq!(
+            validate("32T3X 765\nKK67 28\nQQQJA x\nT55J5"),
+            [
+                Violation::new(1, 5, "invalid card 'X'"),
+                Violation::new(2, 1, "hand has 4 cards instead of 5"),
+                Violation::new(3, 7, "bid \"x\" is not a number"),
+                Violation::new(4, 1, "expected a hand and a bid"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    example::Example,
+    slurp::{opt, pair, separated_list, separated_pair, take_while1, tuple, Res},
+    span::span,
+    validate::{self, Violation},
+    Info, Part, Puzzle, PuzzleError,
+};
+
+#[derive(Hash, Copy, Clone, Debug, PartialEq, Eq)]
+struct Label([u8; 3]);
+
+impl Label {
+    pub fn new(label: &[u8]) -> Result<Self, PuzzleError> {
+        let label = label.try_into().map_err(|_| {
+            PuzzleError::invalid(format!(
+                "label {:?} is not three characters",
+                String::from_utf8_lossy(label)
+            ))
+        })?;
+        Ok(Self(label))
+    }
+
+    pub fn is_start(&self) -> bool {
+        self.0[2] == b'A'
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.0[2] == b'Z'
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Node {
+    left: Label,
+    right: Label,
+}
+
+impl Node {
+    pub fn new(left: &[u8], right: &[u8]) -> Result<Self, PuzzleError> {
+        Ok(Self {
+            left: Label::new(left)?,
+            right: Label::new(right)?,
+        })
+    }
+}
+
+fn element(input: &[u8]) -> Res<&[u8], &[u8]> {
+    take_while1(|b: u8| b.is_ascii_alphanumeric())(input)
+}
+
+fn newline(input: &[u8]) -> Res<&[u8], (Option<u8>, u8)> {
+    pair(opt(b'\r'), b'\n')(input)
+}
+
+type Network = HashMap<Label, Node>;
+
+fn parse_network(input: &[u8]) -> Result<(&[u8], Network), PuzzleError> {
+    let _span = span("parse_network");
+    let parse_line = tuple((
+        element,
+        &b" = ("[..],
+        separated_pair(element, &b", "[..], element),
+        b')',
+    ));
+    let (i, map) = separated_list(parse_line, newline)(input)?;
+    let network = map
+        .iter()
+        .map(|(key, _, (left, right), _)| Ok((Label::new(key)?, Node::new(left, right)?)))
+        .collect::<Result<HashMap<Label, Node>, PuzzleError>>()?;
+    Ok((i, network))
+}
+
+/// Follows one step of the route from `label`.
+fn step(network: &Network, label: Label, direction: u8) -> Result<Label, PuzzleError> {
+    let Node { left, right } = network.get(&label).ok_or_else(|| {
+        PuzzleError::invalid(format!(
+            "no node named {}",
+            String::from_utf8_lossy(&label.0)
+        ))
+    })?;
+    match direction {
+        b'L' => Ok(*left),
+        b'R' => Ok(*right),
+        _ => Err(PuzzleError::invalid("invalid character in route")),
+    }
+}
+
+/// How many times the route has to be followed from `start` to end up on an end node, failing
+/// when the walk comes back to a node it already started the route from without getting there.
+fn cycle_count(network: &Network, start: Label, route: &[u8]) -> Result<usize, PuzzleError> {
+    let _span = span("cycle_count");
+    let mut loc = start;
+    let mut count = 0;
+    let mut seen = HashSet::new();
+    loop {
+        if !seen.insert(loc) {
+            return Err(PuzzleError::NoSolution);
+        }
+        for &ch in route {
+            loc = step(network, loc, ch)?;
+        }
+        count += 1;
+        if loc.is_end() {
+            return Ok(count);
+        }
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while a != 0 {
+        let tmp = a;
+        a = b % a;
+        b = tmp;
+    }
+    b
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+pub struct Documents {
+    route: Vec<u8>,
+    network: Network,
+}
+
+/// Checks the route and that every node reads `AAA = (BBB, CCC)` with three character labels.
+fn validate(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut lines = validate::lines(input);
+    let Some((line, route)) = lines.next() else {
+        return vec![Violation::new(1, 1, "the input is empty")];
+    };
+    if route.is_empty() {
+        violations.push(Violation::new(line, 1, "the route is empty"));
+    }
+    for (column, ch) in validate::columns(route) {
+        if ch != 'L' && ch != 'R' {
+            violations.push(Violation::new(
+                line,
+                column,
+                format!("route step {:?} is not L or R", ch),
+            ));
+        }
+    }
+    if let Some((line, text)) = lines.next() {
+        if !text.is_empty() {
+            violations.push(Violation::new(line, 1, "expected a blank line"));
+        }
+    }
+    for (line, text) in lines {
+        validate_node(line, text, &mut violations);
+    }
+    violations
+}
+
+fn validate_node(line: usize, text: &str, violations: &mut Vec<Violation>) {
+    let mut rest = text;
+    let mut column = 1;
+    for separator in [" = (", ", ", ")"] {
+        let len = rest
+            .find(|ch: char| !ch.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        let label = &rest[..len];
+        if len != 3 {
+            violations.push(Violation::new(
+                line,
+                column,
+                format!("label {:?} is not three letters or digits", label),
+            ));
+        }
+        rest = &rest[len..];
+        column += len;
+
+        let Some(after) = rest.strip_prefix(separator) else {
+            violations.push(Violation::new(
+                line,
+                column,
+                format!("expected {:?}", separator),
+            ));
+            return;
+        };
+        rest = after;
+        column += separator.len();
+    }
+    if !rest.is_empty() {
+        violations.push(Violation::new(
+            line,
+            column,
+            "unexpected text after the node",
+        ));
+    }
+}
+
+pub struct Day8;
+
+impl Puzzle for Day8 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 8,
+        title: "Haunted Wasteland",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Documents;
+    type Output1 = usize;
+    type Output2 = usize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const VALIDATOR: Option<validate::Validator> = Some(validate);
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        let input = input.as_bytes();
+        let (i, route) = take_while1(|b: u8| b.is_ascii_alphabetic())(input)?;
+        let (i, _) = pair(newline, newline)(i)?;
+        let (i, network) = parse_network(i)?;
+        if !i.is_empty() {
+            return Err(PuzzleError::invalid("unparsed input after the network"));
+        }
+
+        Ok(Documents {
+            route: route.to_vec(),
+            network,
+        })
+    }
+
+    fn part1(documents: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        let Documents { route, network } = documents;
+
+        let mut i = 0;
+        let mut current = Label(*b"AAA");
+        // Being on the same node at the same point of the route again means going round forever.
+        let mut seen = HashSet::new();
+        for (position, &ch) in route.iter().enumerate().cycle() {
+            if !seen.insert((current, position)) {
+                return Err(PuzzleError::NoSolution);
+            }
+            current = step(network, current, ch)?;
+            i += 1;
+            if current.is_end() {
+                return Ok(i);
+            }
+        }
+        Err(PuzzleError::NoSolution)
+    }
+
+    fn part2(documents: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        let Documents { route, network } = documents;
+
+        let ghosts = network
+            .keys()
+            .filter_map(|&key| key.is_start().then_some(key))
+            .collect::<Vec<_>>();
+
+        let lcm = ghosts
+            .into_iter()
+            .map(|ghost| cycle_count(network, ghost, route))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .reduce(lcm)
+            .ok_or(PuzzleError::NoSolution)?;
+
+        Ok(lcm * route.len())
+    }
+}
+
+const EXAMPLE1: &str = include_str!("../input/2023/day8.ex1");
+
+const EXAMPLE2: &str = include_str!("../input/2023/day8.ex2");
+
+crate::examples! {
+    Day8 {
+        example1: One(EXAMPLE1) => 2,
+        example2: Two(EXAMPLE2) => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slurp::take_while1;
+
+    #[test]
+    fn test_parse() {
+        println!("{:?}", EXAMPLE2);
+        let i = EXAMPLE2.as_bytes();
+        println!("{:?}", i);
+        let (i, item) = take_while1(|b: u8| b.is_ascii_alphanumeric())(i).unwrap();
+        dbg!(item);
+        assert_eq!(item, b"LR");
+        let (i, item) = newline(i).unwrap();
+        dbg!(item);
+        assert_eq!(item, (None, b'\n'));
+        let (i, item) = newline(i).unwrap();
+        dbg!(item);
+        assert_eq!(item, (None, b'\n'));
+        assert_eq!(i[0..=2], b"11A"[..], "{}", std::ascii::escape_default(i[0]));
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(35, 21), 7)
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(7, 11), 77)
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            Day8::solve("LR\n\nAA = (BBB, CCC)\n", Part::One),
+            Err(PuzzleError::invalid("label \"AA\" is not three characters"))
+        );
+        assert_eq!(
+            Day8::solve("LR\n\nAAA = (BBB, ZZZ)\n", Part::One),
+            Err(PuzzleError::invalid("no node named BBB"))
+        );
+        let unreachable = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n";
+        assert_eq!(
+            Day8::solve(unreachable, Part::One),
+            Err(PuzzleError::NoSolution)
+        );
+        assert_eq!(
+            Day8::solve(unreachable, Part::Two),
+            Err(PuzzleError::NoSolution)
+        );
+    }
+
+    #[test]
+    fn validator() {
+        assert_eq!(validate(EXAMPLE1), []);
+        assert_eq!(validate(EXAMPLE2), []);
+        assert_eq!(
+            validate(
+                "LXR\n\nAAA = (BB, CCC)\nAAAA = (BBB, CCC)\nAAA = [BBB, CCC]\nAAA = (BBB, CCC) x"
+            ),
+            [
+                Violation::new(1, 2, "route step 'X' is not L or R"),
+                Violation::new(3, 8, "label \"BB\" is not three letters or digits"),
+                Violation::new(4, 1, "label \"AAAA\" is not three letters or digits"),
+                Violation::new(5, 4, "expected \" = (\""),
+                Violation::new(6, 17, "unexpected text after the node"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::{fmt::Debug, str::FromStr};
+
+use crate::{
+    example::Example,
+    slurp::{map_res, separated_list, take_while1, Res},
+    Info, Part, Puzzle, PuzzleError,
+};
+
+pub struct Sequence {
+    history: Vec<isize>,
+}
+
+impl Sequence {
+    /// The differences of a single value are empty, and extrapolate like a run of zeroes.
+    pub fn new(history: impl Into<Vec<isize>>) -> Self {
+        Self {
+            history: history.into(),
+        }
+    }
+
+    pub fn first(&self) -> isize {
+        *self.history.first().unwrap()
+    }
+
+    pub fn last(&self) -> isize {
+        *self.history.last().unwrap()
+    }
+
+    pub fn differences(&self) -> Sequence {
+        Self::new(
+            self.history
+                .windows(2)
+                .map(|win| win[1] - win[0])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn zero(&self) -> bool {
+        self.history.iter().all(|&i| i == 0)
+    }
+
+    pub fn extrapolate(&self) -> isize {
+        let diff = self.differences();
+        if diff.zero() {
+            self.last()
+        } else {
+            self.last() + diff.extrapolate()
+        }
+    }
+
+    pub fn extrapolate_back(&self) -> isize {
+        let diff = self.differences();
+        if diff.zero() {
+            self.first()
+        } else {
+            self.first() - diff.extrapolate_back()
+        }
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = PuzzleError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        fn num(input: &str) -> Res<&str, isize> {
+            map_res(
+                take_while1(|ch: char| !ch.is_ascii_whitespace()),
+                |i: &str| i.parse::<isize>(),
+            )(input)
+        }
+
+        let (_, nums) = separated_list(num, ' ')(input)?;
+        if nums.is_empty() {
+            return Err(PuzzleError::invalid("empty sequence"));
+        }
+        Ok(Self::new(nums))
+    }
+}
+
+impl Debug for Sequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for n in &self.history {
+            f.write_fmt(format_args!("{} ", *n))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day9;
+
+impl Puzzle for Day9 {
+    const INFO: Info = Info {
+        year: 2023,
+        day: 9,
+        title: "Mirage Maintenance",
+        parts: &[Part::One, Part::Two],
+    };
+
+    type Parsed = Vec<Sequence>;
+    type Output1 = isize;
+    type Output2 = isize;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Parsed, PuzzleError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(sequences: &Self::Parsed) -> Result<Self::Output1, PuzzleError> {
+        Ok(sequences.iter().map(|seq| seq.extrapolate()).sum())
+    }
+
+    fn part2(sequences: &Self::Parsed) -> Result<Self::Output2, PuzzleError> {
+        Ok(sequences.iter().map(|seq| seq.extrapolate_back()).sum())
+    }
+}
+
+const EXAMPLE: &str = include_str!("../input/2023/day9.ex1");
+
+crate::examples! {
+    Day9 {
+        example1: One(EXAMPLE) => 114,
+        example2: Two(EXAMPLE) => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day9;
+    use crate::{Part, Puzzle};
+
+    #[test]
+    fn short_sequences() {
+        assert_eq!(Day9::solve("1 5\n7\n", Part::One), Ok("16".to_string()));
+        assert_eq!(Day9::solve("1 5\n7\n", Part::Two), Ok("4".to_string()));
+    }
+}